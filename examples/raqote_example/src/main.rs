@@ -4,7 +4,7 @@ use minifb::{Window, WindowOptions};
 use motion::{
     collision::{shape::Shape, Collision2d},
     event_loop::EventLoopBuilder,
-    forces::r#move::{move2d, Direction2d},
+    forces::r#move::move2d,
     formulas::elastic_collision,
     obj::obj_2d::Object2dBuilder,
     vec::vec_2d::vec2,
@@ -41,9 +41,9 @@ fn main() {
         .radius(20.0)
         .mass(1.0)
         .density(2.0)
-        .acceleration(vec2(2000.0, 10.0))
+        .acceleration(vec2(0.0, 0.0))
         .shape(Shape::Circle)
-        .velocity(vec2(2000.0, 10.0))
+        .velocity(vec2(300.0, 0.0))
         .build();
 
     let mut obj2 = Object2dBuilder::new()
@@ -64,17 +64,9 @@ fn main() {
                 0xff, 0xff, 0xff, 0xff,
             ));
             let mut pb = PathBuilder::new();
-            obj1.apply(&move2d(
-                Direction2d::X,
-                config.delta_time,
-                config.delta_time,
-            ));
-            obj2.apply(&move2d(
-                Direction2d::X,
-                config.delta_time,
-                config.delta_time,
-            ));
-            let collide = Collision2d::new(obj1, obj2);
+            obj1.apply(&move2d(config.delta_time));
+            obj2.apply(&move2d(config.delta_time));
+            let collide = Collision2d::new(obj1.clone(), obj2.clone());
 
             if collide.collider() {
                 let u = elastic_collision::calculate(
@@ -91,10 +83,7 @@ fn main() {
                 );
 
                 obj1.velocity = u;
-                obj1.acceleration = u;
-
                 obj2.velocity = u2;
-                obj2.acceleration = u2;
             }
 
             pb.arc(obj1.vec.x, obj1.vec.y, obj1.radius, 0.0, 2.0 * PI);
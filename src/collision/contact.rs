@@ -0,0 +1,234 @@
+use crate::vec::vec_2d::Vec2d;
+
+use super::polygon::{convex_half_plane, Convex};
+
+/// Describes how two colliding objects touch each other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact2d {
+    /// Unit vector pointing from the first object towards the second one.
+    pub normal: Vec2d,
+    /// How deep the objects overlap along the normal.
+    pub depth: f32,
+    /// The point where the objects touch, in world coordinates.
+    pub point: Vec2d,
+}
+
+/// A contact between two objects that also tells which children of compound objects, or which
+/// segments of heightfields, touch, see [`Shape::Compound`](super::shape::Shape::Compound) and
+/// [`Shape::Heightfield`](super::shape::Shape::Heightfield).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChildContact {
+    /// The index of the child or segment of the first object, or `None` if it has none.
+    pub child1: Option<usize>,
+    /// The index of the child or segment of the second object, or `None` if it has none.
+    pub child2: Option<usize>,
+    /// How the children touch.
+    pub contact: Contact2d,
+}
+
+impl Contact2d {
+    /// Returns the same contact seen from the other object, with the normal flipped.
+    #[must_use]
+    pub fn flipped(&self) -> Self {
+        Contact2d {
+            normal: -self.normal,
+            ..*self
+        }
+    }
+}
+
+/// Computes the contact between two circles, touching circles are considered colliding.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::contact::circle_circle, vec::vec_2d::vec2};
+/// let contact = circle_circle(vec2(0.0, 0.0), 2.0, vec2(3.0, 0.0), 2.0).unwrap();
+/// assert_eq!(contact.normal, vec2(1.0, 0.0));
+/// assert_eq!(contact.depth, 1.0);
+/// assert_eq!(contact.point, vec2(1.5, 0.0));
+/// ```
+#[must_use]
+pub fn circle_circle(
+    center1: Vec2d,
+    radius1: f32,
+    center2: Vec2d,
+    radius2: f32,
+) -> Option<Contact2d> {
+    let offset = center1.component(center2);
+    let distance = offset.magnitude();
+    let radii = radius1 + radius2;
+    if distance > radii {
+        return None;
+    }
+    let normal = if distance > 0.0 {
+        offset / distance
+    } else {
+        Vec2d::new(1.0, 0.0)
+    };
+    let depth = radii - distance;
+    Some(Contact2d {
+        normal,
+        depth,
+        point: center1 + normal * (radius1 - 0.5 * depth),
+    })
+}
+
+/// Computes the contact between two axis-aligned bounding boxes given by their corners in world
+/// coordinates, boxes that only share an edge are not considered colliding.
+///
+/// The normal is chosen along the axis with the smallest overlap.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::contact::aabb_aabb, vec::vec_2d::vec2};
+/// let contact = aabb_aabb(vec2(0.0, 0.0), vec2(2.0, 2.0), vec2(1.5, 0.0), vec2(3.5, 2.0)).unwrap();
+/// assert_eq!(contact.normal, vec2(1.0, 0.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(1.75, 1.0));
+/// ```
+#[must_use]
+pub fn aabb_aabb(min1: Vec2d, max1: Vec2d, min2: Vec2d, max2: Vec2d) -> Option<Contact2d> {
+    let overlap_min = Vec2d::new(min1.x.max(min2.x), min1.y.max(min2.y));
+    let overlap_max = Vec2d::new(max1.x.min(max2.x), max1.y.min(max2.y));
+    let overlap = overlap_min.component(overlap_max);
+    if overlap.x <= 0.0 || overlap.y <= 0.0 {
+        return None;
+    }
+    let between = ((min1 + max1) / 2.0).component((min2 + max2) / 2.0);
+    let (normal, depth) = if overlap.x < overlap.y {
+        (Vec2d::new(sign(between.x), 0.0), overlap.x)
+    } else {
+        (Vec2d::new(0.0, sign(between.y)), overlap.y)
+    };
+    Some(Contact2d {
+        normal,
+        depth,
+        point: (overlap_min + overlap_max) / 2.0,
+    })
+}
+
+/// Computes the contact between a circle and an axis-aligned bounding box given by its corners
+/// in world coordinates, the normal points from the circle towards the box.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::contact::circle_aabb, vec::vec_2d::vec2};
+/// let contact = circle_aabb(vec2(1.0, -0.5), 1.0, vec2(0.0, 0.0), vec2(4.0, 1.0)).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(1.0, 0.0));
+/// ```
+#[must_use]
+pub fn circle_aabb(center: Vec2d, radius: f32, min: Vec2d, max: Vec2d) -> Option<Contact2d> {
+    let closest = Vec2d::new(center.x.clamp(min.x, max.x), center.y.clamp(min.y, max.y));
+    let offset = center.component(closest);
+    let distance2 = offset * offset;
+    if distance2 > 0.0 {
+        if distance2 > radius * radius {
+            return None;
+        }
+        let distance = offset.magnitude();
+        return Some(Contact2d {
+            normal: offset / distance,
+            depth: radius - distance,
+            point: closest,
+        });
+    }
+
+    // The center is inside the box, the circle is pushed out through the nearest side.
+    let sides = [
+        (
+            center.x - min.x,
+            Vec2d::new(1.0, 0.0),
+            Vec2d::new(min.x, center.y),
+        ),
+        (
+            max.x - center.x,
+            Vec2d::new(-1.0, 0.0),
+            Vec2d::new(max.x, center.y),
+        ),
+        (
+            center.y - min.y,
+            Vec2d::new(0.0, 1.0),
+            Vec2d::new(center.x, min.y),
+        ),
+        (
+            max.y - center.y,
+            Vec2d::new(0.0, -1.0),
+            Vec2d::new(center.x, max.y),
+        ),
+    ];
+    let mut nearest = sides[0];
+    for side in sides {
+        if side.0 < nearest.0 {
+            nearest = side;
+        }
+    }
+    let (distance, normal, point) = nearest;
+    Some(Contact2d {
+        normal,
+        depth: radius + distance,
+        point,
+    })
+}
+
+/// Computes the contact between a circle and a half-plane through `point` with the unit outward
+/// `normal`, the normal of the contact points from the circle towards the half-plane.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::contact::circle_half_plane, vec::vec_2d::vec2};
+/// let contact = circle_half_plane(vec2(3.0, -0.5), 1.0, vec2(0.0, 0.0), vec2(0.0, -1.0)).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(3.0, 0.25));
+/// ```
+#[must_use]
+pub fn circle_half_plane(
+    center: Vec2d,
+    radius: f32,
+    point: Vec2d,
+    normal: Vec2d,
+) -> Option<Contact2d> {
+    let distance = point.component(center) * normal;
+    if distance > radius {
+        return None;
+    }
+    let depth = radius - distance;
+    Some(Contact2d {
+        normal: -normal,
+        depth,
+        point: center - normal * (radius - 0.5 * depth),
+    })
+}
+
+/// Computes the contact between an axis-aligned bounding box given by its corners in world
+/// coordinates and a half-plane through `point` with the unit outward `normal`. The normal of the
+/// contact points from the box towards the half-plane, and the contact point is the middle of
+/// the corners of the box behind the boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::contact::aabb_half_plane, vec::vec_2d::vec2};
+/// let contact = aabb_half_plane(vec2(0.0, -1.0), vec2(2.0, 0.5), vec2(0.0, 0.0), vec2(0.0, -1.0)).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(1.0, 0.5));
+/// ```
+#[must_use]
+pub fn aabb_half_plane(min: Vec2d, max: Vec2d, point: Vec2d, normal: Vec2d) -> Option<Contact2d> {
+    convex_half_plane(&Convex::aabb(min, max), point, normal)
+}
+
+fn sign(value: f32) -> f32 {
+    if value < 0.0 {
+        -1.0
+    } else {
+        1.0
+    }
+}
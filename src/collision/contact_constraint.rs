@@ -0,0 +1,240 @@
+use crate::{
+    constraint::Constraint,
+    obj::{material::Material, obj_2d::Object2d},
+    vec::vec_2d::Vec2d,
+};
+
+use super::{
+    contact::{ChildContact, Contact2d},
+    response::separate,
+    Collision2d,
+};
+
+/// The approach speed below which contacts do not bounce, so resting objects settle instead of
+/// hopping on the speed gravity gives them every step.
+pub const RESTITUTION_THRESHOLD: f32 = 1.0;
+
+/// The smallest dot product between the normals of a contact in two consecutive steps for it to
+/// be considered the same contact and warm started.
+const WARM_START_ALIGNMENT: f32 = 0.9;
+
+/// A contact between two objects solved as a [`Constraint`], so it can be solved together with
+/// joints and other contacts.
+///
+/// The normal impulse only pushes the objects apart and makes them bounce with the combined
+/// restitution of their materials. The friction impulse is limited by the combined Coulomb
+/// friction coefficients times the normal impulse. Both impulses are accumulated over the
+/// iterations of a step, so an iteration may take back part of what the previous ones applied.
+///
+/// The accumulated impulses are kept when the contact is carried over to the next step with
+/// [`ContactConstraint::warm_started`], and applied again by [`Constraint::warm_start`] before
+/// iterating. Resting contacts then start from the impulse that held them the step before, which
+/// is what lets stacks of objects come to rest.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::{contact_constraint::ContactConstraint, shape::Shape, Collision2d},
+/// #     constraint::solve,
+/// #     obj::{body_type::BodyType, obj_2d::Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut ball = Object2dBuilder::new()
+///     .mass(1.0)
+///     .radius(1.0)
+///     .shape(Shape::Circle)
+///     .velocity(vec2(0.0, 3.0))
+///     .build();
+/// let mut floor = Object2dBuilder::new()
+///     .position(vec2(0.0, 0.9))
+///     .shape(Shape::AABB(vec2(-5.0, 0.0), vec2(5.0, 1.0)))
+///     .body_type(BodyType::Static)
+///     .build();
+///
+/// let contact = Collision2d::new(ball.clone(), floor.clone()).contact().unwrap();
+/// solve(&mut ContactConstraint::new(contact), &mut ball, &mut floor, 1.0 / 60.0, 8);
+///
+/// // The default materials keep a fifth of the speed.
+/// assert!((ball.velocity - vec2(0.0, -0.6)).magnitude() < 1e-5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContactConstraint {
+    /// The contact being solved, its normal points from the first object towards the second.
+    pub contact: Contact2d,
+    /// The child or segment of the first object that touches, or `None` if it has none.
+    pub child1: Option<usize>,
+    /// The child or segment of the second object that touches, or `None` if it has none.
+    pub child2: Option<usize>,
+    r1: Vec2d,
+    r2: Vec2d,
+    tangent: Vec2d,
+    normal_mass: f32,
+    tangent_mass: f32,
+    target_speed: f32,
+    material: Material,
+    normal_impulse: f32,
+    tangent_impulse: f32,
+}
+
+impl ContactConstraint {
+    /// Creates a new `ContactConstraint` for a contact between two objects.
+    #[must_use]
+    pub fn new(contact: Contact2d) -> Self {
+        Self {
+            contact,
+            child1: None,
+            child2: None,
+            r1: Vec2d::ZERO,
+            r2: Vec2d::ZERO,
+            tangent: Vec2d::ZERO,
+            normal_mass: 0.0,
+            tangent_mass: 0.0,
+            target_speed: 0.0,
+            material: Material::default(),
+            normal_impulse: 0.0,
+            tangent_impulse: 0.0,
+        }
+    }
+
+    /// Returns the normal impulse accumulated during the last step.
+    #[must_use]
+    pub fn normal_impulse(&self) -> f32 {
+        self.normal_impulse
+    }
+
+    /// Returns the friction impulse accumulated during the last step.
+    #[must_use]
+    pub fn tangent_impulse(&self) -> f32 {
+        self.tangent_impulse
+    }
+
+    /// Carries the impulses accumulated by `previous`, the same contact during the last step, over
+    /// to this one. They are dropped if the normal turned too much since then, or if `previous`
+    /// was between other children of compound objects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{contact_constraint::ContactConstraint, shape::Shape, Collision2d},
+    /// #     constraint::solve,
+    /// #     obj::{body_type::BodyType, material::Material, obj_2d::Object2dBuilder},
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let material = Material { restitution: 0.0, static_friction: 0.5, kinetic_friction: 0.3 };
+    /// let mut crate_ = Object2dBuilder::new()
+    ///     .mass(2.0)
+    ///     .position(vec2(0.0, -0.45))
+    ///     .velocity(vec2(0.0, 0.5))
+    ///     .shape(Shape::AABB(vec2(-0.5, -0.5), vec2(0.5, 0.5)))
+    ///     .material(material)
+    ///     .build();
+    /// let mut floor = Object2dBuilder::new()
+    ///     .shape(Shape::AABB(vec2(-5.0, 0.0), vec2(5.0, 1.0)))
+    ///     .body_type(BodyType::Static)
+    ///     .material(material)
+    ///     .build();
+    ///
+    /// let contact = Collision2d::new(crate_.clone(), floor.clone()).contact().unwrap();
+    /// let mut last_step = ContactConstraint::new(contact);
+    /// solve(&mut last_step, &mut crate_, &mut floor, 1.0 / 60.0, 8);
+    /// assert_eq!(last_step.normal_impulse(), 1.0);
+    ///
+    /// // The next step starts from the impulse that stopped the crate.
+    /// let contact = ContactConstraint::new(contact).warm_started(&last_step);
+    /// assert_eq!(contact.normal_impulse(), 1.0);
+    /// ```
+    #[must_use]
+    pub fn warm_started(mut self, previous: &ContactConstraint) -> Self {
+        let same_children = (self.child1, self.child2) == (previous.child1, previous.child2);
+        if same_children && self.contact.normal * previous.contact.normal >= WARM_START_ALIGNMENT {
+            self.normal_impulse = previous.normal_impulse;
+            self.tangent_impulse = previous.tangent_impulse;
+        }
+        self
+    }
+
+    /// Applies `impulse` along `direction` to `obj2` and its opposite to `obj1`.
+    fn apply(&self, obj1: &mut Object2d, obj2: &mut Object2d, direction: Vec2d, impulse: f32) {
+        obj1.apply_impulse_at(-direction * impulse, self.r1);
+        obj2.apply_impulse_at(direction * impulse, self.r2);
+    }
+
+    /// Returns the velocity of the contact point of `obj2` relative to the one of `obj1`.
+    fn relative_velocity(&self, obj1: &Object2d, obj2: &Object2d) -> Vec2d {
+        obj2.point_velocity(self.r2) - obj1.point_velocity(self.r1)
+    }
+}
+
+impl Constraint for ContactConstraint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, _delta_time: f32) {
+        let normal = self.contact.normal;
+        self.r1 = obj1.vec.component(self.contact.point);
+        self.r2 = obj2.vec.component(self.contact.point);
+        self.tangent = normal.perp();
+        self.normal_mass =
+            inverse(obj1.inverse_mass_at(self.r1, normal) + obj2.inverse_mass_at(self.r2, normal));
+        self.tangent_mass = inverse(
+            obj1.inverse_mass_at(self.r1, self.tangent)
+                + obj2.inverse_mass_at(self.r2, self.tangent),
+        );
+        self.material = obj1.material.combine(&obj2.material);
+        let normal_speed = self.relative_velocity(obj1, obj2) * normal;
+        self.target_speed = if -normal_speed > RESTITUTION_THRESHOLD {
+            -self.material.restitution * normal_speed
+        } else {
+            0.0
+        };
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        self.apply(obj1, obj2, self.contact.normal, self.normal_impulse);
+        self.apply(obj1, obj2, self.tangent, self.tangent_impulse);
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let normal = self.contact.normal;
+        let normal_speed = self.relative_velocity(obj1, obj2) * normal;
+        let accumulated =
+            (self.normal_impulse + (self.target_speed - normal_speed) * self.normal_mass).max(0.0);
+        self.apply(obj1, obj2, normal, accumulated - self.normal_impulse);
+        self.normal_impulse = accumulated;
+
+        let tangent_speed = self.relative_velocity(obj1, obj2) * self.tangent;
+        let mut accumulated = self.tangent_impulse - tangent_speed * self.tangent_mass;
+        if accumulated.abs() > self.material.static_friction * self.normal_impulse {
+            let kinetic = self.material.kinetic_friction * self.normal_impulse;
+            accumulated = accumulated.clamp(-kinetic, kinetic);
+        }
+        self.apply(obj1, obj2, self.tangent, accumulated - self.tangent_impulse);
+        self.tangent_impulse = accumulated;
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        if let Some(contact) =
+            Collision2d::new(obj1.clone(), obj2.clone()).contact_between(self.child1, self.child2)
+        {
+            separate(obj1, obj2, &contact);
+        }
+    }
+}
+
+impl From<ChildContact> for ContactConstraint {
+    fn from(child_contact: ChildContact) -> Self {
+        Self {
+            child1: child_contact.child1,
+            child2: child_contact.child2,
+            ..Self::new(child_contact.contact)
+        }
+    }
+}
+
+/// Returns `1 / value`, or zero if `value` is not positive.
+fn inverse(value: f32) -> f32 {
+    if value > 0.0 {
+        1.0 / value
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,178 @@
+use crate::vec::vec_2d::Vec2d;
+
+use super::{
+    contact::Contact2d,
+    gjk::{self, Search, Vertex},
+    support::{face, Support},
+};
+
+/// The largest number of vertices of the polytope, which bounds how closely curved shapes are
+/// followed.
+const MAX_VERTICES: usize = 32;
+
+/// The smallest overlap between the sides of two shapes for them to touch at both of its ends.
+const MIN_OVERLAP: f32 = 1e-3;
+
+/// How much farther than the closest edge a new vertex must lie for the polytope to keep growing.
+const TOLERANCE: f32 = 1e-6;
+
+/// Computes how two overlapping convex shapes touch with the Expanding Polytope Algorithm, or
+/// `None` if they are apart or only touch.
+///
+/// The normal of the contact points from `shape1` towards `shape2`, and the depth is the
+/// shortest distance to move them apart. The point lies halfway between the deepest points of
+/// each shape inside the other.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::{epa::penetration, polygon::Convex, support::Circle},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let crate_ = Convex::aabb(vec2(0.0, 0.0), vec2(2.0, 2.0));
+/// let ball = Circle::new(vec2(2.5, 1.0), 1.0);
+///
+/// let contact = penetration(&crate_, &ball).unwrap();
+/// assert!((contact.normal - vec2(1.0, 0.0)).magnitude() < 1e-3);
+/// assert!((contact.depth - 0.5).abs() < 1e-3);
+/// assert!((contact.point - vec2(1.75, 1.0)).magnitude() < 1e-3);
+/// ```
+#[must_use]
+pub fn penetration<A: Support + ?Sized, B: Support + ?Sized>(
+    shape1: &A,
+    shape2: &B,
+) -> Option<Contact2d> {
+    let Search::Overlap(simplex) = gjk::search(shape1, shape2) else {
+        return None;
+    };
+    let mut polytope = [Vertex::default(); MAX_VERTICES];
+    polytope[..simplex.len].copy_from_slice(&simplex.vertices[..simplex.len]);
+    let mut len = simplex.len;
+
+    // Grow a point or segment touching the origin into a triangle around it.
+    if len == 1 {
+        polytope[1] = Vertex::support(shape1, shape2, Vec2d::new(-1.0, 0.0));
+        len = 2;
+    }
+    if len == 2 {
+        let across = polytope[0].point.component(polytope[1].point).perp();
+        if across == Vec2d::ZERO {
+            return None;
+        }
+        let (third, opposite) = (
+            Vertex::support(shape1, shape2, across),
+            Vertex::support(shape1, shape2, -across),
+        );
+        polytope[2] = if third.point * across > opposite.point * -across {
+            third
+        } else {
+            opposite
+        };
+        len = 3;
+    }
+    let [a, b, c] = [polytope[0], polytope[1], polytope[2]].map(|vertex| vertex.point);
+    if a.component(b).cross(a.component(c)) < 0.0 {
+        polytope.swap(1, 2);
+    }
+
+    loop {
+        let (index, normal, distance) = closest_edge(&polytope[..len])?;
+        let vertex = Vertex::support(shape1, shape2, normal);
+        if vertex.point * normal - distance <= TOLERANCE || len == MAX_VERTICES {
+            if distance <= 0.0 {
+                return None;
+            }
+            let (a, b) = (polytope[index], polytope[(index + 1) % len]);
+            let edge = a.point.component(b.point);
+            let t = ((normal * distance - a.point) * edge / (edge * edge)).clamp(0.0, 1.0);
+            let point1 = a.point1 + a.point1.component(b.point1) * t;
+            let point2 = a.point2 + a.point2.component(b.point2) * t;
+            return Some(Contact2d {
+                normal,
+                depth: distance,
+                point: (point1 + point2) / 2.0,
+            });
+        }
+        polytope.copy_within(index + 1..len, index + 2);
+        polytope[index + 1] = vertex;
+        len += 1;
+    }
+}
+
+/// Computes up to two points where two overlapping convex shapes touch, with the normal of
+/// [`penetration`].
+///
+/// Sides of the shapes lying against each other touch at both ends of their overlap, each with
+/// its own depth, which keeps shapes resting on a side from rocking. Other shapes touch at the
+/// single point of [`penetration`].
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::{epa::manifold, polygon::Convex}, vec::vec_2d::vec2};
+/// let crate_ = Convex::aabb(vec2(0.0, 0.0), vec2(2.0, 2.0));
+/// let floor = Convex::aabb(vec2(-5.0, 1.75), vec2(5.0, 3.0));
+///
+/// let [right, left] = manifold(&crate_, &floor).map(Option::unwrap);
+/// assert!((left.point - vec2(0.0, 1.875)).magnitude() < 1e-3);
+/// assert!((right.point - vec2(2.0, 1.875)).magnitude() < 1e-3);
+/// assert!((left.depth - 0.25).abs() < 1e-3);
+/// ```
+#[must_use]
+pub fn manifold<A: Support + ?Sized, B: Support + ?Sized>(
+    shape1: &A,
+    shape2: &B,
+) -> [Option<Contact2d>; 2] {
+    let Some(contact) = penetration(shape1, shape2) else {
+        return [None, None];
+    };
+    let (normal, tangent) = (contact.normal, contact.normal.perp());
+    let side1 = face(shape1, normal);
+    let (last, first) = face(shape2, -normal);
+    let side2 = (first, last);
+    let low = (side1.0 * tangent).max(side2.0 * tangent);
+    let high = (side1.1 * tangent).min(side2.1 * tangent);
+    if high - low <= MIN_OVERLAP {
+        return [Some(contact), None];
+    }
+
+    let points = [low, high].map(|along| {
+        let (point1, point2) = (at(side1, tangent, along), at(side2, tangent, along));
+        let depth = point2.component(point1) * normal;
+        (depth > 0.0).then(|| Contact2d {
+            normal,
+            depth,
+            point: (point1 + point2) / 2.0,
+        })
+    });
+    if points.iter().all(Option::is_none) {
+        return [Some(contact), None];
+    }
+    points
+}
+
+/// Returns the point of a side at `along` on the `tangent` axis.
+fn at((first, last): (Vec2d, Vec2d), tangent: Vec2d, along: f32) -> Vec2d {
+    let span = first.component(last) * tangent;
+    if span <= 0.0 {
+        return first;
+    }
+    first + first.component(last) * ((along - first * tangent) / span)
+}
+
+/// Returns the edge of a counterclockwise polytope closest to the origin: the index of its first
+/// vertex, its outward unit normal and its distance to the origin.
+fn closest_edge(polytope: &[Vertex]) -> Option<(usize, Vec2d, f32)> {
+    (0..polytope.len())
+        .filter_map(|index| {
+            let a = polytope[index].point;
+            let edge = a.component(polytope[(index + 1) % polytope.len()].point);
+            let length = edge.magnitude();
+            (length > 0.0).then(|| {
+                let normal = Vec2d::new(edge.y, -edge.x) / length;
+                (index, normal, a * normal)
+            })
+        })
+        .min_by(|(_, _, distance1), (_, _, distance2)| distance1.total_cmp(distance2))
+}
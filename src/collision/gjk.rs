@@ -0,0 +1,219 @@
+use crate::vec::vec_2d::Vec2d;
+
+use super::support::Support;
+
+/// The largest number of points added to the simplex before giving up on a search.
+const MAX_ITERATIONS: usize = 32;
+
+/// How close to the origin the simplex must get for the shapes to overlap.
+const TOLERANCE: f32 = 1e-5;
+
+/// How much closer to the origin a new point must bring the simplex, relative to its squared
+/// distance, for the search to go on.
+const PROGRESS: f32 = 1e-6;
+
+/// Describes how far apart two separated convex shapes are.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance {
+    /// The distance between the shapes.
+    pub distance: f32,
+    /// The point of the first shape closest to the second one.
+    pub point1: Vec2d,
+    /// The point of the second shape closest to the first one.
+    pub point2: Vec2d,
+}
+
+/// A point of the Minkowski difference of two shapes, with the points of each shape it comes
+/// from.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Vertex {
+    pub point: Vec2d,
+    pub point1: Vec2d,
+    pub point2: Vec2d,
+}
+
+impl Vertex {
+    /// Returns the point of the Minkowski difference of two shapes farthest along `direction`.
+    pub(crate) fn support<A: Support + ?Sized, B: Support + ?Sized>(
+        shape1: &A,
+        shape2: &B,
+        direction: Vec2d,
+    ) -> Self {
+        let (point1, point2) = (shape1.support(direction), shape2.support(-direction));
+        Self {
+            point: point1 - point2,
+            point1,
+            point2,
+        }
+    }
+}
+
+/// The simplex of a search, up to a triangle, with the weights giving its point closest to the
+/// origin.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Simplex {
+    pub vertices: [Vertex; 3],
+    pub len: usize,
+    weights: [f32; 3],
+}
+
+impl Simplex {
+    /// Returns the point of the simplex closest to the origin, keeping only the vertices it
+    /// depends on, or the origin itself if the simplex contains it.
+    fn reduce(&mut self) -> Vec2d {
+        match self.len {
+            1 => {
+                self.weights[0] = 1.0;
+                self.vertices[0].point
+            }
+            2 => self.reduce_to(0, 1),
+            _ => {
+                let [oldest, older, newest] = self.vertices.map(|vertex| vertex.point);
+                let sides = [
+                    oldest.component(older).cross(-oldest),
+                    older.component(newest).cross(-older),
+                    newest.component(oldest).cross(-newest),
+                ];
+                if sides.iter().all(|side| *side >= 0.0) || sides.iter().all(|side| *side <= 0.0) {
+                    return Vec2d::ZERO;
+                }
+                let (edge1, edge2) = [(0, 1), (1, 2), (2, 0)]
+                    .into_iter()
+                    .min_by(|(i1, j1), (i2, j2)| {
+                        let d1 = self.closest_on_edge(*i1, *j1).0.magnitude();
+                        let d2 = self.closest_on_edge(*i2, *j2).0.magnitude();
+                        d1.total_cmp(&d2)
+                    })
+                    .unwrap_or((0, 1));
+                self.reduce_to(edge1, edge2)
+            }
+        }
+    }
+
+    /// Keeps the part of the edge between the vertices at `i` and `j` closest to the origin and
+    /// returns the closest point.
+    fn reduce_to(&mut self, i: usize, j: usize) -> Vec2d {
+        let (closest, t) = self.closest_on_edge(i, j);
+        let (start, end) = (self.vertices[i], self.vertices[j]);
+        if t <= 0.0 {
+            self.vertices[0] = start;
+            self.weights[0] = 1.0;
+            self.len = 1;
+        } else if t >= 1.0 {
+            self.vertices[0] = end;
+            self.weights[0] = 1.0;
+            self.len = 1;
+        } else {
+            self.vertices[..2].copy_from_slice(&[start, end]);
+            self.weights[..2].copy_from_slice(&[1.0 - t, t]);
+            self.len = 2;
+        }
+        closest
+    }
+
+    /// Returns the point of the edge between the vertices at `i` and `j` closest to the origin,
+    /// and how far along the edge it lies.
+    fn closest_on_edge(&self, i: usize, j: usize) -> (Vec2d, f32) {
+        let (start, end) = (self.vertices[i].point, self.vertices[j].point);
+        let edge = start.component(end);
+        let length2 = edge * edge;
+        if length2 == 0.0 {
+            return (start, 0.0);
+        }
+        let t = (-(start * edge) / length2).clamp(0.0, 1.0);
+        (start + edge * t, t)
+    }
+
+    /// Returns the points of the shapes the closest point of the simplex comes from.
+    fn witnesses(&self) -> (Vec2d, Vec2d) {
+        self.vertices[..self.len].iter().zip(self.weights).fold(
+            (Vec2d::ZERO, Vec2d::ZERO),
+            |(point1, point2), (vertex, weight)| {
+                (
+                    point1 + vertex.point1 * weight,
+                    point2 + vertex.point2 * weight,
+                )
+            },
+        )
+    }
+}
+
+/// The outcome of a search.
+pub(crate) enum Search {
+    /// The shapes overlap, the simplex surrounds or touches the origin.
+    Overlap(Simplex),
+    /// The shapes are apart.
+    Separated(Distance),
+}
+
+/// Searches the Minkowski difference of two convex shapes for its point closest to the origin
+/// with the Gilbert-Johnson-Keerthi algorithm.
+pub(crate) fn search<A: Support + ?Sized, B: Support + ?Sized>(shape1: &A, shape2: &B) -> Search {
+    let mut simplex = Simplex::default();
+    simplex.vertices[0] = Vertex::support(shape1, shape2, Vec2d::new(1.0, 0.0));
+    simplex.len = 1;
+    let mut closest = simplex.reduce();
+    for _ in 0..MAX_ITERATIONS {
+        let length2 = closest * closest;
+        if length2 <= TOLERANCE * TOLERANCE {
+            return Search::Overlap(simplex);
+        }
+        let vertex = Vertex::support(shape1, shape2, -closest);
+        if length2 - closest * vertex.point <= PROGRESS * length2 {
+            break;
+        }
+        simplex.vertices[simplex.len] = vertex;
+        simplex.len += 1;
+        closest = simplex.reduce();
+    }
+    let (point1, point2) = simplex.witnesses();
+    Search::Separated(Distance {
+        distance: closest.magnitude(),
+        point1,
+        point2,
+    })
+}
+
+/// Returns whether two convex shapes overlap, shapes that only touch count as overlapping.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::{gjk::intersects, support::Circle}, vec::vec_2d::vec2};
+/// let circle = Circle::new(vec2(0.0, 0.0), 1.0);
+/// assert!(intersects(&circle, &vec2(0.5, 0.5)));
+/// assert!(!intersects(&circle, &Circle::new(vec2(3.0, 0.0), 1.0)));
+/// ```
+#[must_use]
+pub fn intersects<A: Support + ?Sized, B: Support + ?Sized>(shape1: &A, shape2: &B) -> bool {
+    matches!(search(shape1, shape2), Search::Overlap(_))
+}
+
+/// Computes the distance between two convex shapes and their closest points, or `None` if they
+/// overlap.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::{gjk::distance, polygon::Convex, support::Circle},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let crate_ = Convex::aabb(vec2(0.0, 0.0), vec2(2.0, 2.0));
+/// let ball = Circle::new(vec2(5.0, 1.0), 1.0);
+///
+/// let gap = distance(&crate_, &ball).unwrap();
+/// assert!((gap.distance - 2.0).abs() < 1e-4);
+/// assert!((gap.point1 - vec2(2.0, 1.0)).magnitude() < 1e-3);
+/// assert!((gap.point2 - vec2(4.0, 1.0)).magnitude() < 1e-3);
+/// ```
+#[must_use]
+pub fn distance<A: Support + ?Sized, B: Support + ?Sized>(
+    shape1: &A,
+    shape2: &B,
+) -> Option<Distance> {
+    match search(shape1, shape2) {
+        Search::Overlap(_) => None,
+        Search::Separated(distance) => Some(distance),
+    }
+}
@@ -0,0 +1,327 @@
+use core::ops::Range;
+
+use crate::{collision::shape::Shape, obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{
+    contact::Contact2d,
+    polygon::Convex,
+    support::{face, Support},
+};
+
+/// A terrain profile placed in the world, the form of [`Shape::Heightfield`] used for collision
+/// detection.
+///
+/// The heights are sampled every `spacing` along `x` starting at `position`, and joined by
+/// straight segments. Everything below the profile, with greater `y` like the rest of the screen
+/// coordinates, is solid.
+///
+/// Each segment collides on its own, with the normal of its face. Corners are only used at the
+/// convex joins between segments, so objects sliding over a flat or concave join never catch on
+/// it.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::heightfield::Heightfield, vec::vec_2d::vec2};
+/// static HILL: [f32; 3] = [0.0, -1.0, 0.0];
+///
+/// let hill = Heightfield::new(&HILL, 2.0, vec2(0.0, 5.0));
+/// assert_eq!(hill.height_at(1.0), Some(4.5));
+/// assert!(hill.contains(vec2(2.0, 4.5)));
+/// assert_eq!(hill.height_at(5.0), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Heightfield<'a> {
+    heights: &'a [f32],
+    spacing: f32,
+    position: Vec2d,
+}
+
+impl<'a> Heightfield<'a> {
+    /// Creates a new `Heightfield` from heights sampled every `spacing` along `x`, relative to
+    /// `position`.
+    #[must_use]
+    pub fn new(heights: &'a [f32], spacing: f32, position: Vec2d) -> Self {
+        Self {
+            heights,
+            spacing,
+            position,
+        }
+    }
+
+    /// Returns the heightfield of an object with a heightfield shape.
+    pub(crate) fn of(obj: &'a Object2d) -> Option<Self> {
+        match &obj.shape {
+            Shape::Heightfield(heights, spacing) => Some(Self::new(heights, *spacing, obj.vec)),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of segments of the profile.
+    #[must_use]
+    pub fn segments(&self) -> usize {
+        self.heights.len().saturating_sub(1)
+    }
+
+    /// Returns the sample at `index` in world coordinates.
+    // Profiles have far fewer samples than an `f32` counts exactly.
+    #[allow(clippy::cast_precision_loss)]
+    fn vertex(&self, index: usize) -> Option<Vec2d> {
+        let height = *self.heights.get(index)?;
+        Some(self.position + Vec2d::new(index as f32 * self.spacing, height))
+    }
+
+    /// Returns the ends of the segment at `index` in world coordinates, from left to right.
+    #[must_use]
+    pub fn segment(&self, index: usize) -> Option<(Vec2d, Vec2d)> {
+        Some((self.vertex(index)?, self.vertex(index + 1)?))
+    }
+
+    /// Returns the unit normal of the segment at `index`, pointing up and out of the terrain.
+    #[must_use]
+    pub fn normal(&self, index: usize) -> Option<Vec2d> {
+        let (a, b) = self.segment(index)?;
+        let edge = a.component(b);
+        let length = edge.magnitude();
+        (length > 0.0).then(|| -edge.perp() / length)
+    }
+
+    /// Returns the height of the profile at `x` in world coordinates, or `None` outside of it.
+    // The offset is checked to lie between zero and the number of segments before truncating it.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    #[must_use]
+    pub fn height_at(&self, x: f32) -> Option<f32> {
+        let offset = (x - self.position.x) / self.spacing;
+        if !(0.0..=self.segments() as f32).contains(&offset) {
+            return None;
+        }
+        let index = (offset as usize).min(self.segments().saturating_sub(1));
+        let (a, b) = self.segment(index)?;
+        let t = (x - a.x) / (b.x - a.x);
+        Some(a.y + (b.y - a.y) * t)
+    }
+
+    /// Returns whether a point lies below the profile.
+    #[must_use]
+    pub fn contains(&self, point: Vec2d) -> bool {
+        self.height_at(point.x)
+            .is_some_and(|height| point.y >= height)
+    }
+
+    /// Returns the indices of the segments overlapping the range of `x` from `min` to `max`.
+    // The offsets are clamped between zero and the last segment before truncating them.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    #[must_use]
+    pub fn segments_between(&self, min: f32, max: f32) -> Range<usize> {
+        let last = self.segments() as f32;
+        let (start, end) = (
+            (min - self.position.x) / self.spacing,
+            (max - self.position.x) / self.spacing,
+        );
+        if self.segments() == 0 || end < 0.0 || start > last || start.is_nan() || end.is_nan() {
+            return 0..0;
+        }
+        let start = start.clamp(0.0, last - 1.0) as usize;
+        let end = end.clamp(0.0, last - 1.0) as usize;
+        start..end + 1
+    }
+
+    /// Returns whether the join at the sample `index` bulges out of the terrain, which is the
+    /// case at both ends of the profile.
+    fn is_convex(&self, index: usize) -> bool {
+        if index == 0 || index + 1 >= self.heights.len() {
+            return true;
+        }
+        let (before, at, after) = (
+            self.heights[index - 1],
+            self.heights[index],
+            self.heights[index + 1],
+        );
+        after - at > at - before
+    }
+
+    /// Computes the contact between a circle and the segment at `index`, the normal points from
+    /// the circle towards the terrain.
+    ///
+    /// Every join belongs to a single segment: a convex join to the segment on its left, whose
+    /// corner it is, and a flat or concave join to the face of the segment on its right. So a
+    /// circle rolling over the profile gets a single contact with a smooth normal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::heightfield::Heightfield, vec::vec_2d::vec2};
+    /// static FLAT: [f32; 3] = [0.0, 0.0, 0.0];
+    /// let ground = Heightfield::new(&FLAT, 1.0, vec2(0.0, 0.0));
+    ///
+    /// // A ball right over the join between the two segments only touches one of them.
+    /// let contact = ground.circle_contact(1, vec2(1.0, -0.5), 1.0).unwrap();
+    /// assert_eq!(contact.normal, vec2(0.0, 1.0));
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert!(ground.circle_contact(0, vec2(1.0, -0.5), 1.0).is_none());
+    /// assert!(ground.circle_contact(1, vec2(0.5, -0.5), 1.0).is_none());
+    /// ```
+    #[must_use]
+    pub fn circle_contact(&self, index: usize, center: Vec2d, radius: f32) -> Option<Contact2d> {
+        let (a, b) = self.segment(index)?;
+        let normal = self.normal(index)?;
+        let edge = a.component(b);
+        let t = (a.component(center) * edge) / (edge * edge);
+        if index > 0 && (t < 0.0 || (t <= 0.0 && self.is_convex(index))) {
+            return None;
+        }
+        let corner = if t < 0.0 {
+            Some(a)
+        } else if t >= 1.0 {
+            if !self.is_convex(index + 1) {
+                return None;
+            }
+            Some(b)
+        } else {
+            None
+        };
+
+        let (outward, distance) = match corner {
+            Some(corner) => {
+                let offset = corner.component(center);
+                let distance = offset.magnitude();
+                let outward = if distance > 0.0 {
+                    offset / distance
+                } else {
+                    normal
+                };
+                (outward, distance)
+            }
+            None => (normal, a.component(center) * normal),
+        };
+        if distance > radius {
+            return None;
+        }
+        let depth = radius - distance;
+        Some(Contact2d {
+            normal: -outward,
+            depth,
+            point: center - outward * (radius - 0.5 * depth),
+        })
+    }
+
+    /// Computes the contact between a convex polygon and the segment at `index`, the normal
+    /// points from the polygon towards the terrain.
+    ///
+    /// Only the part of the polygon over the span of the segment is tested, against the face of
+    /// the segment, so boxes sliding from one segment to the next never catch on the join.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::{heightfield::Heightfield, polygon::Convex}, vec::vec_2d::vec2};
+    /// static FLAT: [f32; 3] = [0.0, 0.0, 0.0];
+    /// let ground = Heightfield::new(&FLAT, 1.0, vec2(0.0, 0.0));
+    /// let crate_ = Convex::aabb(vec2(0.5, -1.0), vec2(1.5, 0.25));
+    ///
+    /// let contact = ground.convex_contact(0, &crate_).unwrap();
+    /// assert_eq!(contact.normal, vec2(0.0, 1.0));
+    /// assert_eq!(contact.depth, 0.25);
+    /// assert_eq!(contact.point, vec2(0.75, 0.25));
+    /// ```
+    #[must_use]
+    pub fn convex_contact(&self, index: usize, convex: &Convex) -> Option<Contact2d> {
+        let (a, b) = self.segment(index)?;
+        let normal = self.normal(index)?;
+        let within = |point: &Vec2d| (a.x..=b.x).contains(&point.x);
+        let crossings = convex.edges().flat_map(|(p, q, _)| {
+            [a.x, b.x]
+                .into_iter()
+                .filter(move |x| (p.x - x) * (q.x - x) < 0.0)
+                .map(move |x| p + p.component(q) * ((x - p.x) / (q.x - p.x)))
+        });
+
+        let (mut depth, mut sum, mut count) = (0.0_f32, Vec2d::ZERO, 0.0);
+        for point in convex.vertices().filter(within).chain(crossings) {
+            let below = -(a.component(point) * normal);
+            if below > 0.0 {
+                depth = depth.max(below);
+                sum += point;
+                count += 1.0;
+            }
+        }
+        if depth <= 0.0 {
+            return None;
+        }
+        Some(Contact2d {
+            normal: -normal,
+            depth,
+            point: sum / count,
+        })
+    }
+
+    /// Computes the contact between a convex shape and the segment at `index`, the normal points
+    /// from the shape towards the terrain.
+    ///
+    /// Only the part of the side of the shape facing the segment over the span of the segment is
+    /// tested, like in [`Heightfield::convex_contact`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{heightfield::Heightfield, support::Circle},
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// static FLAT: [f32; 3] = [0.0, 0.0, 0.0];
+    /// let ground = Heightfield::new(&FLAT, 1.0, vec2(0.0, 0.0));
+    /// let ball = Circle::new(vec2(0.5, -0.5), 1.0);
+    ///
+    /// let contact = ground.support_contact(0, &ball).unwrap();
+    /// assert_eq!(contact.normal, vec2(0.0, 1.0));
+    /// assert!((contact.depth - 0.5).abs() < 1e-3);
+    /// assert!(ground.support_contact(1, &ball).is_none());
+    /// ```
+    #[must_use]
+    pub fn support_contact<S: Support + ?Sized>(
+        &self,
+        index: usize,
+        shape: &S,
+    ) -> Option<Contact2d> {
+        let (a, b) = self.segment(index)?;
+        let normal = self.normal(index)?;
+        let (mut first, mut last) = face(shape, -normal);
+        if first.x > last.x {
+            (first, last) = (last, first);
+        }
+        if last.x < a.x || first.x > b.x {
+            return None;
+        }
+        let at = |x: f32| first + first.component(last) * ((x - first.x) / (last.x - first.x));
+        let ends = [
+            if first.x < a.x { at(a.x) } else { first },
+            if last.x > b.x { at(b.x) } else { last },
+        ];
+
+        let (mut depth, mut sum, mut count) = (0.0_f32, Vec2d::ZERO, 0.0);
+        for point in ends {
+            let below = -(a.component(point) * normal);
+            if below > 0.0 {
+                depth = depth.max(below);
+                sum += point;
+                count += 1.0;
+            }
+        }
+        if depth <= 0.0 {
+            return None;
+        }
+        Some(Contact2d {
+            normal: -normal,
+            depth,
+            point: sum / count,
+        })
+    }
+}
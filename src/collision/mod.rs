@@ -1,6 +1,20 @@
 use crate::obj::obj_2d::Object2d;
+pub mod contact;
+pub mod contact_constraint;
+pub mod epa;
+pub mod gjk;
+pub mod heightfield;
+pub mod polygon;
+pub mod ray;
+pub mod response;
 pub mod shape;
+pub mod support;
+use contact::{ChildContact, Contact2d};
+use gjk::Distance;
+use heightfield::Heightfield;
+use polygon::Convex;
 use shape::Shape;
+use support::Placed;
 
 /// Represents a 2D collision detection between two objects.
 pub struct Collision2d {
@@ -25,16 +39,27 @@ impl Collision2d {
     /// # Examples
     ///
     /// ```
-    /// let obj1 = Object2d::new(/* parameters */);
-    /// let obj2 = Object2d::new(/* parameters */);
+    /// # use motion::{
+    /// #     collision::{shape::Shape, Collision2d},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let obj1 = Object2dBuilder::new().radius(1.0).shape(Shape::Circle).build();
+    /// let obj2 = Object2dBuilder::new()
+    ///     .position(vec2(1.5, 0.0))
+    ///     .radius(1.0)
+    ///     .shape(Shape::Circle)
+    ///     .build();
     /// let collision = Collision2d::new(obj1, obj2);
+    /// assert_eq!(collision.obj2.vec, vec2(1.5, 0.0));
     /// ```
     #[must_use]
     pub fn new(obj1: Object2d, obj2: Object2d) -> Self {
         Self { obj1, obj2 }
     }
 
-    /// Detects if a collision occurs between the two objects.
+    /// Detects if a collision occurs between the two objects, which is whether
+    /// [`Collision2d::contact`] finds a contact, for any pair of supported shapes.
     ///
     /// # Returns
     ///
@@ -43,49 +68,351 @@ impl Collision2d {
     /// # Examples
     ///
     /// ```
-    /// let obj1 = Object2d::new(/* parameters */);
-    /// let obj2 = Object2d::new(/* parameters */);
-    /// let collision = Collision2d::new(obj1, obj2);
-    /// if collision.collider() {
-    ///     println!("Collision detected!");
-    /// }
+    /// # use motion::{
+    /// #     collision::{shape::Shape, Collision2d},
+    /// #     obj::obj_2d::{Object2d, Object2dBuilder},
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let ball = Object2dBuilder::new().radius(1.0).shape(Shape::Circle).build();
+    /// // The corners of the box are relative to its position.
+    /// let crate_ = Object2dBuilder::new()
+    ///     .position(vec2(0.5, 0.0))
+    ///     .shape(Shape::AABB(vec2(0.0, -1.0), vec2(2.0, 1.0)))
+    ///     .build();
+    ///
+    /// assert!(Collision2d::new(ball.clone(), crate_.clone()).collider());
+    ///
+    /// let far_crate = Object2d { vec: vec2(5.0, 0.0), ..crate_ };
+    /// assert!(!Collision2d::new(ball, far_crate).collider());
     /// ```
     #[must_use]
     pub fn collider(&self) -> bool {
-        match (&self.obj1.shape, &self.obj2.shape) {
-            (Shape::Circle, Shape::Circle) => self.circle_collision(),
-            (Shape::AABB(_, _), Shape::AABB(_, _)) => self.aabb_collision(),
-            _ => false,
-        }
+        self.contact().is_some()
     }
 
-    /// Detects if a collision occurs between two circular shapes.
+    /// Computes how the two objects touch, if they collide.
+    ///
+    /// Circles, AABBs, polygons, custom shapes, half-planes and any pair of them are supported,
+    /// objects without a shape never collide. Custom shapes go through [`epa::penetration`]. For
+    /// compound objects and heightfields this is the deepest contact between their children or
+    /// segments, see [`Collision2d::contacts`].
     ///
     /// # Returns
     ///
-    /// `true` if a collision is detected, `false` otherwise.
+    /// The contact with its normal pointing from `obj1` towards `obj2`, or `None` if there is no collision.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if either shape is not a circle.
-    fn circle_collision(&self) -> bool {
-        if self.obj2.shape != Shape::Circle || self.obj1.shape != Shape::Circle {
-            return false;
+    /// ```
+    /// # use motion::{
+    /// #     collision::{shape::Shape, Collision2d},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let ball = Object2dBuilder::new()
+    ///     .position(vec2(1.0, -0.5))
+    ///     .radius(1.0)
+    ///     .shape(Shape::Circle)
+    ///     .build();
+    /// let floor = Object2dBuilder::new()
+    ///     .position(vec2(0.0, 0.0))
+    ///     .shape(Shape::AABB(vec2(-5.0, 0.0), vec2(5.0, 1.0)))
+    ///     .build();
+    ///
+    /// let contact = Collision2d::new(ball, floor).contact().unwrap();
+    /// assert_eq!(contact.normal, vec2(0.0, 1.0));
+    /// assert_eq!(contact.depth, 0.5);
+    /// ```
+    #[must_use]
+    pub fn contact(&self) -> Option<Contact2d> {
+        let (obj1, obj2) = (&self.obj1, &self.obj2);
+        match (&obj1.shape, &obj2.shape) {
+            (Shape::Compound(_) | Shape::Heightfield(..), _)
+            | (_, Shape::Compound(_) | Shape::Heightfield(..)) => {
+                self.child_contact().map(|child| child.contact)
+            }
+            (Shape::None, _) | (_, Shape::None) => None,
+            (Shape::Custom(_), Shape::HalfPlane(normal)) => {
+                support::support_half_plane(&Placed::of(obj1)?, obj2.vec, *normal)
+            }
+            (Shape::HalfPlane(normal), Shape::Custom(_)) => {
+                support::support_half_plane(&Placed::of(obj2)?, obj1.vec, *normal)
+                    .map(|contact| contact.flipped())
+            }
+            (Shape::Custom(_), _) | (_, Shape::Custom(_)) => {
+                epa::penetration(&Placed::of(obj1)?, &Placed::of(obj2)?)
+            }
+            (Shape::Circle, Shape::Circle) => {
+                contact::circle_circle(obj1.vec, obj1.radius, obj2.vec, obj2.radius)
+            }
+            (Shape::AABB(min1, max1), Shape::AABB(min2, max2)) => contact::aabb_aabb(
+                obj1.vec + *min1,
+                obj1.vec + *max1,
+                obj2.vec + *min2,
+                obj2.vec + *max2,
+            ),
+            (Shape::Circle, Shape::AABB(min, max)) => {
+                contact::circle_aabb(obj1.vec, obj1.radius, obj2.vec + *min, obj2.vec + *max)
+            }
+            (Shape::AABB(min, max), Shape::Circle) => {
+                contact::circle_aabb(obj2.vec, obj2.radius, obj1.vec + *min, obj1.vec + *max)
+                    .map(|contact| contact.flipped())
+            }
+            (Shape::Circle, Shape::HalfPlane(normal)) => {
+                contact::circle_half_plane(obj1.vec, obj1.radius, obj2.vec, *normal)
+            }
+            (Shape::HalfPlane(normal), Shape::Circle) => {
+                contact::circle_half_plane(obj2.vec, obj2.radius, obj1.vec, *normal)
+                    .map(|contact| contact.flipped())
+            }
+            (Shape::AABB(min, max), Shape::HalfPlane(normal)) => {
+                contact::aabb_half_plane(obj1.vec + *min, obj1.vec + *max, obj2.vec, *normal)
+            }
+            (Shape::HalfPlane(normal), Shape::AABB(min, max)) => {
+                contact::aabb_half_plane(obj2.vec + *min, obj2.vec + *max, obj1.vec, *normal)
+                    .map(|contact| contact.flipped())
+            }
+            (
+                Shape::Rect(..) | Shape::Polygon(_),
+                Shape::Rect(..) | Shape::Polygon(_) | Shape::AABB(..),
+            )
+            | (Shape::AABB(..), Shape::Rect(..) | Shape::Polygon(_)) => {
+                polygon::convex_convex(&Convex::of(obj1)?, &Convex::of(obj2)?)
+            }
+            (Shape::Circle, Shape::Rect(..) | Shape::Polygon(_)) => {
+                polygon::circle_convex(obj1.vec, obj1.radius, &Convex::of(obj2)?)
+            }
+            (Shape::Rect(..) | Shape::Polygon(_), Shape::Circle) => {
+                polygon::circle_convex(obj2.vec, obj2.radius, &Convex::of(obj1)?)
+                    .map(|contact| contact.flipped())
+            }
+            (Shape::Rect(..) | Shape::Polygon(_), Shape::HalfPlane(normal)) => {
+                polygon::convex_half_plane(&Convex::of(obj1)?, obj2.vec, *normal)
+            }
+            (Shape::HalfPlane(normal), Shape::Rect(..) | Shape::Polygon(_)) => {
+                polygon::convex_half_plane(&Convex::of(obj2)?, obj1.vec, *normal)
+                    .map(|contact| contact.flipped())
+            }
+            _ => None,
         }
-        let distance = self.obj1.vec.distance(self.obj2.vec);
-        let radius1 = self.obj1.radius;
-        let radius2 = self.obj2.radius;
-        distance <= (radius1 + radius2)
     }
 
-    /// Detects if a collision occurs between two axis-aligned bounding boxes (AABBs).
+    /// Iterates over the contacts between the children of the objects, telling which children
+    /// touch. The children of a heightfield are its segments under the other object, objects
+    /// that are neither compounds nor heightfields take part as a whole. Polygons and custom
+    /// shapes may touch at two points, see [`polygon::convex_manifold`] and [`epa::manifold`],
+    /// which then come as two contacts between the same children.
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// `true` if a collision is detected, `false` otherwise.
-    fn aabb_collision(&self) -> bool {
-        let vec1 = self.obj1.shape.get_aabb();
-        let vec2 = self.obj2.shape.get_aabb();
-        vec1.0.x < vec2.1.x && vec1.1.x > vec2.0.x && vec1.0.y < vec2.1.y && vec1.1.y > vec2.0.y
+    /// ```
+    /// # use motion::{
+    /// #     collision::{shape::{ChildShape, Shape, Shared}, Collision2d},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::{vec2, Vec2d},
+    /// # };
+    /// static DUMBBELL: [ChildShape; 2] = [
+    ///     ChildShape::circle(Vec2d::new(-2.0, 0.0), 1.0),
+    ///     ChildShape::circle(Vec2d::new(2.0, 0.0), 1.0),
+    /// ];
+    /// let dumbbell = Object2dBuilder::new().mass(2.0).shape(Shape::Compound(Shared::from_static(&DUMBBELL))).build();
+    /// let ball = Object2dBuilder::new()
+    ///     .mass(1.0)
+    ///     .position(vec2(3.5, 0.0))
+    ///     .radius(1.0)
+    ///     .shape(Shape::Circle)
+    ///     .build();
+    ///
+    /// let mut contacts = Collision2d::new(dumbbell, ball).contacts();
+    /// let hit = contacts.next().unwrap();
+    /// assert_eq!((hit.child1, hit.child2), (Some(1), None));
+    /// assert_eq!(hit.contact.depth, 0.5);
+    /// assert!(contacts.next().is_none());
+    /// ```
+    pub fn contacts(&self) -> impl Iterator<Item = ChildContact> {
+        let (obj1, obj2) = (self.obj1.clone(), self.obj2.clone());
+        children(&obj1, &obj2).flat_map(move |child1| {
+            let children2 = children(&obj2, &obj1);
+            let collision = Collision2d::new(obj1.clone(), obj2.clone());
+            children2.flat_map(move |child2| {
+                collision
+                    .manifold_between(child1, child2)
+                    .map(move |contact| ChildContact {
+                        child1,
+                        child2,
+                        contact,
+                    })
+            })
+        })
+    }
+
+    /// Computes the points where a child of each object touch: the manifold of two polygons or
+    /// of a custom shape and another convex shape, see [`epa::manifold`], or the single contact
+    /// of [`Collision2d::contact_between`] otherwise.
+    fn manifold_between(
+        &self,
+        child1: Option<usize>,
+        child2: Option<usize>,
+    ) -> impl Iterator<Item = Contact2d> {
+        let parts = part(&self.obj1, child1).zip(part(&self.obj2, child2));
+        let manifold = parts.and_then(|(part1, part2)| match (&part1.shape, &part2.shape) {
+            (
+                Shape::Rect(..) | Shape::Polygon(_),
+                Shape::Rect(..) | Shape::Polygon(_) | Shape::AABB(..),
+            )
+            | (Shape::AABB(..), Shape::Rect(..) | Shape::Polygon(_)) => Some(
+                polygon::convex_manifold(&Convex::of(&part1)?, &Convex::of(&part2)?),
+            ),
+            (
+                Shape::Custom(_),
+                Shape::Circle
+                | Shape::AABB(..)
+                | Shape::Rect(..)
+                | Shape::Polygon(_)
+                | Shape::Custom(_),
+            )
+            | (
+                Shape::Circle | Shape::AABB(..) | Shape::Rect(..) | Shape::Polygon(_),
+                Shape::Custom(_),
+            ) => Some(epa::manifold(&Placed::of(&part1)?, &Placed::of(&part2)?)),
+            _ => None,
+        });
+        let single = match manifold {
+            Some(_) => None,
+            None => self.contact_between(child1, child2),
+        };
+        single
+            .into_iter()
+            .chain(manifold.into_iter().flatten().flatten())
+    }
+
+    /// Computes the contact between a child of each object, see [`Collision2d::contacts`]. A
+    /// child is a child of a compound or a segment of a heightfield, `None` stands for the whole
+    /// object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{shape::{Shape, Shared}, Collision2d},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// static STEPS: [f32; 3] = [0.0, 0.0, -1.0];
+    /// let ground = Object2dBuilder::new()
+    ///     .shape(Shape::Heightfield(Shared::from_static(&STEPS), 2.0))
+    ///     .build();
+    /// let ball = Object2dBuilder::new()
+    ///     .position(vec2(0.5, -0.5))
+    ///     .radius(1.0)
+    ///     .shape(Shape::Circle)
+    ///     .build();
+    ///
+    /// let collision = Collision2d::new(ball, ground);
+    /// let contact = collision.contact_between(None, Some(0)).unwrap();
+    /// assert_eq!(contact.normal, vec2(0.0, 1.0));
+    /// assert_eq!(contact.depth, 0.5);
+    /// assert!(collision.contact_between(None, Some(1)).is_none());
+    /// ```
+    #[must_use]
+    pub fn contact_between(
+        &self,
+        child1: Option<usize>,
+        child2: Option<usize>,
+    ) -> Option<Contact2d> {
+        let (obj1, obj2) = (&self.obj1, &self.obj2);
+        if let (Shape::Heightfield(..), Some(segment)) = (&obj2.shape, child2) {
+            return terrain_contact(&part(obj1, child1)?, obj2, segment);
+        }
+        if let (Shape::Heightfield(..), Some(segment)) = (&obj1.shape, child1) {
+            return terrain_contact(&part(obj2, child2)?, obj1, segment)
+                .map(|contact| contact.flipped());
+        }
+        Collision2d::new(part(obj1, child1)?, part(obj2, child2)?).contact()
+    }
+
+    /// Computes the distance between two objects with convex shapes and their closest points, see
+    /// [`gjk::distance`]. Returns `None` if they overlap or if a shape is not convex: half-planes,
+    /// compounds and heightfields. Objects without a shape count as points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{shape::Shape, Collision2d},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let ball = Object2dBuilder::new()
+    ///     .position(vec2(0.0, -3.0))
+    ///     .radius(1.0)
+    ///     .shape(Shape::Circle)
+    ///     .build();
+    /// let floor = Object2dBuilder::new()
+    ///     .shape(Shape::AABB(vec2(-5.0, 0.0), vec2(5.0, 1.0)))
+    ///     .build();
+    ///
+    /// let gap = Collision2d::new(ball, floor).distance().unwrap();
+    /// assert!((gap.distance - 2.0).abs() < 1e-4);
+    /// assert!((gap.point1 - vec2(0.0, -2.0)).magnitude() < 1e-3);
+    /// ```
+    #[must_use]
+    pub fn distance(&self) -> Option<Distance> {
+        gjk::distance(&Placed::of(&self.obj1)?, &Placed::of(&self.obj2)?)
+    }
+
+    /// Returns the deepest contact between the children of the objects, see
+    /// [`Collision2d::contacts`].
+    #[must_use]
+    pub fn child_contact(&self) -> Option<ChildContact> {
+        self.contacts()
+            .max_by(|hit1, hit2| hit1.contact.depth.total_cmp(&hit2.contact.depth))
+    }
+}
+
+/// Returns the part of an object that `child` refers to: one of its children, or the whole object
+/// for `None`.
+pub(crate) fn part(obj: &Object2d, child: Option<usize>) -> Option<Object2d> {
+    match child {
+        Some(index) => obj.child(index),
+        None => Some(obj.clone()),
+    }
+}
+
+/// Iterates over the children of an object that may touch `other`: the children of a compound,
+/// the segments of a heightfield under `other`, or `None` for the object itself otherwise.
+fn children(obj: &Object2d, other: &Object2d) -> impl Iterator<Item = Option<usize>> {
+    let indices = match &obj.shape {
+        Shape::Compound(children) => Some(0..children.len()),
+        Shape::Heightfield(..) => Some(
+            Heightfield::of(obj)
+                .zip(other.bounding_box())
+                .map_or(0..0, |(field, (min, max))| {
+                    field.segments_between(min.x, max.x)
+                }),
+        ),
+        _ => None,
+    };
+    let whole = indices.is_none().then_some(None);
+    whole
+        .into_iter()
+        .chain(indices.into_iter().flatten().map(Some))
+}
+
+/// Computes the contact between an object and a segment of a heightfield, the normal points from
+/// the object towards the terrain.
+fn terrain_contact(obj: &Object2d, terrain: &Object2d, segment: usize) -> Option<Contact2d> {
+    let field = Heightfield::of(terrain)?;
+    match obj.shape {
+        Shape::Circle => field.circle_contact(segment, obj.vec, obj.radius),
+        Shape::AABB(..) | Shape::Rect(..) | Shape::Polygon(_) => {
+            field.convex_contact(segment, &Convex::of(obj)?)
+        }
+        Shape::Custom(_) => field.support_contact(segment, &Placed::of(obj)?),
+        Shape::Compound(_) => obj
+            .children()
+            .filter_map(|child| terrain_contact(&child, terrain, segment))
+            .max_by(|contact1, contact2| contact1.depth.total_cmp(&contact2.depth)),
+        _ => None,
     }
 }
@@ -0,0 +1,454 @@
+use crate::{
+    collision::shape::Shape,
+    formulas::sin::{cos, sin},
+    obj::obj_2d::Object2d,
+    vec::vec_2d::Vec2d,
+};
+
+use super::contact::Contact2d;
+
+/// A convex polygon placed in the world, the common form of AABBs and
+/// [`Shape::Polygon`] used by the separating axis tests of this module.
+///
+/// The vertices may be given in either winding order, the outward normals of the edges are found
+/// from the center of the polygon.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::Convex, vec::vec_2d::{vec2, Vec2d}};
+/// static TRIANGLE: [Vec2d; 3] = [Vec2d::new(0.0, -1.0), Vec2d::new(1.0, 1.0), Vec2d::new(-1.0, 1.0)];
+///
+/// let triangle = Convex::new(&TRIANGLE, vec2(5.0, 5.0), 0.0);
+/// assert_eq!(triangle.vertex(0), vec2(5.0, 4.0));
+/// assert!(triangle.contains(vec2(5.0, 5.5)));
+/// assert!(!triangle.contains(vec2(4.0, 4.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convex {
+    vertices: Vertices,
+    position: Vec2d,
+    sin: f32,
+    cos: f32,
+}
+
+/// Where the vertices of a [`Convex`] come from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Vertices {
+    Corners([Vec2d; 4]),
+    Polygon(&'static [Vec2d]),
+}
+
+impl Convex {
+    /// Creates a new `Convex` from local vertices moved to `position` and rotated by `angle`.
+    #[must_use]
+    pub fn new(vertices: &'static [Vec2d], position: Vec2d, angle: f32) -> Self {
+        let (sin, cos) = if angle == 0.0 {
+            (0.0, 1.0)
+        } else {
+            (sin(angle), cos(angle))
+        };
+        Self {
+            vertices: Vertices::Polygon(vertices),
+            position,
+            sin,
+            cos,
+        }
+    }
+
+    /// Creates a new `Convex` from the minimum and maximum corners of an AABB in world
+    /// coordinates.
+    #[must_use]
+    pub fn aabb(min: Vec2d, max: Vec2d) -> Self {
+        Self::rect(min, max, Vec2d::ZERO, 0.0)
+    }
+
+    /// Creates a new `Convex` from the local minimum and maximum corners of a rectangle moved to
+    /// `position` and rotated by `angle`.
+    #[must_use]
+    pub fn rect(min: Vec2d, max: Vec2d, position: Vec2d, angle: f32) -> Self {
+        let corners = [min, Vec2d::new(max.x, min.y), max, Vec2d::new(min.x, max.y)];
+        Self {
+            vertices: Vertices::Corners(corners),
+            ..Self::new(&[], position, angle)
+        }
+    }
+
+    /// Returns the convex polygon of an object with an AABB, rectangle or polygon shape.
+    pub(crate) fn of(obj: &Object2d) -> Option<Self> {
+        match obj.shape {
+            Shape::AABB(min, max) => Some(Self::aabb(obj.vec + min, obj.vec + max)),
+            Shape::Rect(min, max) => Some(Self::rect(min, max, obj.vec, obj.angle)),
+            Shape::Polygon(vertices) => Some(Self::new(vertices, obj.vec, obj.angle)),
+            _ => None,
+        }
+    }
+
+    /// Returns the vertex at `index` in world coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[must_use]
+    pub fn vertex(&self, index: usize) -> Vec2d {
+        let local = match &self.vertices {
+            Vertices::Corners(corners) => corners[index],
+            Vertices::Polygon(vertices) => vertices[index],
+        };
+        self.position
+            + Vec2d::new(
+                local.x * self.cos - local.y * self.sin,
+                local.x * self.sin + local.y * self.cos,
+            )
+    }
+
+    /// Iterates over the vertices in world coordinates.
+    pub fn vertices(&self) -> impl Iterator<Item = Vec2d> + '_ {
+        (0..self.count()).map(|index| self.vertex(index))
+    }
+
+    /// Iterates over the edges as their first vertex, last vertex and outward unit normal.
+    pub fn edges(&self) -> impl Iterator<Item = (Vec2d, Vec2d, Vec2d)> + '_ {
+        let (count, center) = (self.count(), self.center());
+        (0..count).map(move |index| {
+            let (a, b) = (self.vertex(index), self.vertex((index + 1) % count));
+            let edge = a.component(b);
+            let length = edge.magnitude();
+            let mut normal = if length > 0.0 {
+                edge.perp() / length
+            } else {
+                Vec2d::ZERO
+            };
+            if center.component(a) * normal < 0.0 {
+                normal = -normal;
+            }
+            (a, b, normal)
+        })
+    }
+
+    /// Returns whether a point lies inside the polygon or on its boundary.
+    #[must_use]
+    pub fn contains(&self, point: Vec2d) -> bool {
+        self.count() > 0
+            && self
+                .edges()
+                .all(|(a, _, normal)| a.component(point) * normal <= 0.0)
+    }
+
+    /// Returns the area of the part of the polygon below the horizontal line at `surface`, with
+    /// `y` growing downwards.
+    #[must_use]
+    pub fn area_below(&self, surface: f32) -> f32 {
+        // The polygon is clipped against the line and its area summed with the shoelace formula
+        // as the points of the clipped polygon are found.
+        let (mut first, mut previous, mut twice_area) = (None::<Vec2d>, None::<Vec2d>, 0.0);
+        let mut add = |point: Vec2d| {
+            match previous {
+                Some(previous) => twice_area += previous.cross(point),
+                None => first = Some(point),
+            }
+            previous = Some(point);
+        };
+        for (a, b, _) in self.edges() {
+            let (a_below, b_below) = (a.y >= surface, b.y >= surface);
+            if a_below {
+                add(a);
+            }
+            if a_below != b_below {
+                add(a + a.component(b) * ((surface - a.y) / (b.y - a.y)));
+            }
+        }
+        if let (Some(first), Some(last)) = (first, previous) {
+            twice_area += last.cross(first);
+        }
+        twice_area.abs() / 2.0
+    }
+
+    /// Returns the number of vertices.
+    fn count(&self) -> usize {
+        match &self.vertices {
+            Vertices::Corners(corners) => corners.len(),
+            Vertices::Polygon(vertices) => vertices.len(),
+        }
+    }
+
+    /// Returns the average of the vertices, a point inside the polygon.
+    // Polygons have far fewer vertices than an `f32` counts exactly.
+    #[allow(clippy::cast_precision_loss)]
+    fn center(&self) -> Vec2d {
+        let count = self.count();
+        if count == 0 {
+            return self.position;
+        }
+        self.vertices()
+            .fold(Vec2d::ZERO, |sum, vertex| sum + vertex)
+            / count as f32
+    }
+}
+
+/// Returns the area of a convex polygon given by its vertices.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::area, vec::vec_2d::vec2};
+/// assert_eq!(area(&[vec2(0.0, 0.0), vec2(4.0, 0.0), vec2(0.0, 3.0)]), 6.0);
+/// ```
+#[must_use]
+pub fn area(vertices: &[Vec2d]) -> f32 {
+    twice_signed_area(vertices).abs() / 2.0
+}
+
+/// Returns the moment of inertia of a polygon of unit mass and uniform density around the origin
+/// of its vertices.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::unit_inertia, vec::vec_2d::vec2};
+/// // A 2 by 2 square around the origin, (w² + h²) / 12.
+/// let square = [vec2(-1.0, -1.0), vec2(1.0, -1.0), vec2(1.0, 1.0), vec2(-1.0, 1.0)];
+/// assert!((unit_inertia(&square) - 8.0 / 12.0).abs() < 1e-6);
+/// ```
+#[must_use]
+pub fn unit_inertia(vertices: &[Vec2d]) -> f32 {
+    let twice_area = twice_signed_area(vertices);
+    if twice_area == 0.0 {
+        return 0.0;
+    }
+    let sum: f32 = edges(vertices)
+        .map(|(a, b)| a.cross(b) * (a * a + a * b + b * b))
+        .sum();
+    sum / (6.0 * twice_area)
+}
+
+/// Computes the contact between two convex polygons with the separating axis theorem, polygons
+/// that only share an edge are not considered colliding. The normal points from `convex1`
+/// towards `convex2` and the contact point lies between the points of [`convex_manifold`],
+/// closer to the deeper one.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::{convex_convex, Convex}, vec::vec_2d::{vec2, Vec2d}};
+/// static DIAMOND: [Vec2d; 4] = [
+///     Vec2d::new(0.0, -1.0),
+///     Vec2d::new(1.0, 0.0),
+///     Vec2d::new(0.0, 1.0),
+///     Vec2d::new(-1.0, 0.0),
+/// ];
+/// let diamond = Convex::new(&DIAMOND, vec2(0.0, -0.5), 0.0);
+/// let floor = Convex::aabb(vec2(-5.0, 0.0), vec2(5.0, 1.0));
+///
+/// let contact = convex_convex(&diamond, &floor).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(0.0, 0.25));
+/// ```
+#[must_use]
+pub fn convex_convex(convex1: &Convex, convex2: &Convex) -> Option<Contact2d> {
+    let manifold = convex_manifold(convex1, convex2);
+    let points = manifold.iter().flatten();
+    let deepest = points
+        .clone()
+        .max_by(|contact1, contact2| contact1.depth.total_cmp(&contact2.depth))?;
+    let weight: f32 = points.clone().map(|contact| contact.depth).sum();
+    let sum = points.fold(Vec2d::ZERO, |sum, contact| {
+        sum + contact.point * contact.depth
+    });
+    Some(Contact2d {
+        point: sum / weight,
+        ..*deepest
+    })
+}
+
+/// Computes the points where two convex polygons touch, up to two of them sharing the normal
+/// of [`convex_convex`], each with its own depth.
+///
+/// The face of the polygon that separates them the most is the reference face, and the edge of
+/// the other polygon facing it is clipped to its sides. A box resting flat on another one touches
+/// it at both ends of the overlap, which keeps it from rocking.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::{convex_manifold, Convex}, vec::vec_2d::vec2};
+/// let crate_ = Convex::aabb(vec2(-1.0, -2.0), vec2(1.0, 0.5));
+/// let floor = Convex::aabb(vec2(-5.0, 0.0), vec2(5.0, 1.0));
+///
+/// let [Some(first), Some(second)] = convex_manifold(&crate_, &floor) else { panic!() };
+/// assert_eq!((first.depth, second.depth), (0.5, 0.5));
+/// assert_eq!((first.point.y, second.point.y), (0.25, 0.25));
+/// assert_eq!(first.point.x.abs() + second.point.x.abs(), 2.0);
+/// ```
+#[must_use]
+pub fn convex_manifold(convex1: &Convex, convex2: &Convex) -> [Option<Contact2d>; 2] {
+    let (Some((separation1, face1)), Some((separation2, face2))) = (
+        max_separation(convex1, convex2),
+        max_separation(convex2, convex1),
+    ) else {
+        return [None, None];
+    };
+    if separation1 >= 0.0 || separation2 >= 0.0 {
+        return [None, None];
+    }
+    let ((r1, r2, normal), incident, flip) = if separation1 >= separation2 {
+        (face1, convex2, false)
+    } else {
+        (face2, convex1, true)
+    };
+    let Some((i1, i2, _)) = incident
+        .edges()
+        .min_by(|(_, _, n1), (_, _, n2)| (*n1 * normal).total_cmp(&(*n2 * normal)))
+    else {
+        return [None, None];
+    };
+
+    let tangent = r1.component(r2);
+    let (low, high) = (r1 * tangent, r2 * tangent);
+    [(i1, i2), (i2, i1)].map(|(p, q)| {
+        let (along_p, along_q) = (p * tangent, q * tangent);
+        let point = if along_p < low || along_p > high {
+            let bound = if along_p < low { low } else { high };
+            if (along_q - bound) * (along_p - bound) >= 0.0 {
+                return None;
+            }
+            p + p.component(q) * ((bound - along_p) / (along_q - along_p))
+        } else {
+            p
+        };
+        let depth = -(r1.component(point) * normal);
+        (depth > 0.0).then(|| Contact2d {
+            normal: if flip { -normal } else { normal },
+            depth,
+            point: point + normal * (depth / 2.0),
+        })
+    })
+}
+
+/// Computes the contact between a circle and a convex polygon, the normal points from the circle
+/// towards the polygon.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::{circle_convex, Convex}, vec::vec_2d::vec2};
+/// let floor = Convex::aabb(vec2(-5.0, 0.0), vec2(5.0, 1.0));
+///
+/// let contact = circle_convex(vec2(1.0, -0.5), 1.0, &floor).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(1.0, 0.25));
+/// ```
+#[must_use]
+pub fn circle_convex(center: Vec2d, radius: f32, convex: &Convex) -> Option<Contact2d> {
+    let (separation, face_normal) = convex
+        .edges()
+        .map(|(a, _, normal)| (a.component(center) * normal, normal))
+        .max_by(|(s1, _), (s2, _)| s1.total_cmp(s2))?;
+    if separation > radius {
+        return None;
+    }
+    let (outward, distance) = if separation <= 0.0 {
+        // The center is inside, the circle is pushed out through the nearest edge.
+        (face_normal, separation)
+    } else {
+        let closest = convex
+            .edges()
+            .map(|(a, b, _)| closest_on_segment(center, a, b))
+            .min_by(|p1, p2| {
+                let (d1, d2) = (p1.component(center), p2.component(center));
+                (d1 * d1).total_cmp(&(d2 * d2))
+            })?;
+        let offset = closest.component(center);
+        let distance = offset.magnitude();
+        if distance > radius {
+            return None;
+        }
+        let outward = if distance > 0.0 {
+            offset / distance
+        } else {
+            face_normal
+        };
+        (outward, distance)
+    };
+    let depth = radius - distance;
+    Some(Contact2d {
+        normal: -outward,
+        depth,
+        point: center - outward * (radius - 0.5 * depth),
+    })
+}
+
+/// Computes the contact between a convex polygon and a half-plane through `point` with the unit
+/// outward `normal`. The normal of the contact points from the polygon towards the half-plane,
+/// and the contact point is the middle of the vertices behind the boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::polygon::{convex_half_plane, Convex}, vec::vec_2d::vec2};
+/// let crate_ = Convex::aabb(vec2(0.0, -1.0), vec2(2.0, 0.5));
+///
+/// let contact = convex_half_plane(&crate_, vec2(0.0, 0.0), vec2(0.0, -1.0)).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(1.0, 0.5));
+/// ```
+#[must_use]
+pub fn convex_half_plane(convex: &Convex, point: Vec2d, normal: Vec2d) -> Option<Contact2d> {
+    let (mut depth, mut sum, mut count) = (0.0_f32, Vec2d::ZERO, 0.0);
+    for vertex in convex.vertices() {
+        let behind = -(point.component(vertex) * normal);
+        if behind > 0.0 {
+            depth = depth.max(behind);
+            sum += vertex;
+            count += 1.0;
+        }
+    }
+    if depth <= 0.0 {
+        return None;
+    }
+    Some(Contact2d {
+        normal: -normal,
+        depth,
+        point: sum / count,
+    })
+}
+
+/// Returns the largest distance from an edge of `reference` to the vertices of `other` behind
+/// it, together with that edge. A positive distance separates the polygons.
+fn max_separation(reference: &Convex, other: &Convex) -> Option<(f32, (Vec2d, Vec2d, Vec2d))> {
+    reference
+        .edges()
+        .filter_map(|edge| {
+            other
+                .vertices()
+                .map(|vertex| edge.0.component(vertex) * edge.2)
+                .min_by(f32::total_cmp)
+                .map(|separation| (separation, edge))
+        })
+        .max_by(|(s1, _), (s2, _)| s1.total_cmp(s2))
+}
+
+/// Returns the point of the segment from `a` to `b` closest to `point`.
+pub(crate) fn closest_on_segment(point: Vec2d, a: Vec2d, b: Vec2d) -> Vec2d {
+    let edge = a.component(b);
+    let length2 = edge * edge;
+    if length2 == 0.0 {
+        return a;
+    }
+    let t = ((a.component(point) * edge) / length2).clamp(0.0, 1.0);
+    a + edge * t
+}
+
+/// Returns twice the signed area of a polygon.
+fn twice_signed_area(vertices: &[Vec2d]) -> f32 {
+    edges(vertices).map(|(a, b)| a.cross(b)).sum()
+}
+
+/// Iterates over the edges of a polygon as pairs of consecutive vertices.
+fn edges(vertices: &[Vec2d]) -> impl Iterator<Item = (Vec2d, Vec2d)> + '_ {
+    let count = vertices.len();
+    (0..count).map(move |index| (vertices[index], vertices[(index + 1) % count]))
+}
@@ -0,0 +1,300 @@
+use crate::{
+    collision::shape::Shape, formulas::sqrt::sqrt, obj::obj_2d::Object2d, vec::vec_2d::Vec2d,
+};
+
+use super::{
+    gjk,
+    heightfield::Heightfield,
+    polygon::Convex,
+    support::{Placed, Support},
+};
+
+/// The largest number of steps a ray advances towards a custom shape before missing it.
+const MAX_ADVANCES: usize = 32;
+
+/// How close to a custom shape the ray must get to hit it.
+const ADVANCE_TOLERANCE: f32 = 1e-4;
+
+/// A half line used to find the objects lying along a direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray2d {
+    /// The point the ray starts from.
+    pub origin: Vec2d,
+    /// The unit vector the ray points to.
+    pub direction: Vec2d,
+    /// The length of the ray, hits farther than this are ignored.
+    pub max_distance: f32,
+}
+
+/// Describes where a ray hits an object.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// The distance from the origin of the ray to the hit.
+    pub distance: f32,
+    /// The point where the ray hits the object, in world coordinates.
+    pub point: Vec2d,
+    /// The unit normal of the surface of the object at the hit.
+    pub normal: Vec2d,
+}
+
+impl Ray2d {
+    /// Creates a new `Ray2d`, the direction is normalized.
+    ///
+    /// # Parameters
+    ///
+    /// - `origin`: The point the ray starts from.
+    /// - `direction`: The direction of the ray, it must not be zero.
+    /// - `max_distance`: The length of the ray.
+    #[must_use]
+    pub fn new(origin: Vec2d, direction: Vec2d, max_distance: f32) -> Self {
+        Ray2d {
+            origin,
+            direction: direction / direction.magnitude(),
+            max_distance,
+        }
+    }
+
+    /// Creates a new `Ray2d` going from `from` to `to`.
+    #[must_use]
+    pub fn between(from: Vec2d, to: Vec2d) -> Self {
+        let offset = from.component(to);
+        Ray2d::new(from, offset, offset.magnitude())
+    }
+
+    /// Returns the point of the ray at `distance` from its origin.
+    #[must_use]
+    pub fn at(&self, distance: f32) -> Vec2d {
+        self.origin + self.direction * distance
+    }
+
+    /// Finds where the ray first enters the shape of an object.
+    ///
+    /// Objects without a shape and objects containing the origin of the ray are never hit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{ray::Ray2d, shape::Shape},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let wall = Object2dBuilder::new()
+    ///     .position(vec2(5.0, 0.0))
+    ///     .shape(Shape::AABB(vec2(0.0, -5.0), vec2(1.0, 5.0)))
+    ///     .build();
+    ///
+    /// let hit = Ray2d::new(vec2(0.0, 0.0), vec2(1.0, 0.0), 10.0).cast(&wall).unwrap();
+    /// assert_eq!(hit.distance, 5.0);
+    /// assert_eq!(hit.normal, vec2(-1.0, 0.0));
+    ///
+    /// assert!(Ray2d::new(vec2(0.0, 0.0), vec2(1.0, 0.0), 4.0).cast(&wall).is_none());
+    /// ```
+    #[must_use]
+    pub fn cast(&self, obj: &Object2d) -> Option<RayHit> {
+        let hit = match obj.shape {
+            Shape::None => None,
+            Shape::Circle => self.cast_circle(obj.vec, obj.radius),
+            Shape::AABB(min, max) => self.cast_aabb(obj.vec + min, obj.vec + max),
+            Shape::HalfPlane(normal) => self.cast_half_plane(obj.vec, normal),
+            Shape::Rect(..) | Shape::Polygon(_) => self.cast_convex(&Convex::of(obj)?),
+            Shape::Heightfield(..) => self.cast_heightfield(&Heightfield::of(obj)?),
+            Shape::Custom(_) => self.cast_support(&Placed::of(obj)?),
+            Shape::Compound(_) => obj
+                .children()
+                .filter_map(|child| self.cast(&child))
+                .min_by(|hit1, hit2| hit1.distance.total_cmp(&hit2.distance)),
+        }?;
+        (hit.distance <= self.max_distance).then_some(hit)
+    }
+
+    fn cast_circle(&self, center: Vec2d, radius: f32) -> Option<RayHit> {
+        let offset = center.component(self.origin);
+        let projection = offset * self.direction;
+        let outside = offset * offset - radius * radius;
+        if outside <= 0.0 || projection > 0.0 {
+            return None;
+        }
+        let discriminant = projection * projection - outside;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let distance = -projection - sqrt(discriminant);
+        let point = self.at(distance);
+        Some(RayHit {
+            distance,
+            point,
+            normal: center.component(point) / radius,
+        })
+    }
+
+    fn cast_half_plane(&self, point: Vec2d, normal: Vec2d) -> Option<RayHit> {
+        let height = point.component(self.origin) * normal;
+        let approach = self.direction * normal;
+        if height <= 0.0 || approach >= 0.0 {
+            return None;
+        }
+        let distance = -height / approach;
+        Some(RayHit {
+            distance,
+            point: self.at(distance),
+            normal,
+        })
+    }
+
+    fn cast_aabb(&self, min: Vec2d, max: Vec2d) -> Option<RayHit> {
+        let inside =
+            (min.x..=max.x).contains(&self.origin.x) && (min.y..=max.y).contains(&self.origin.y);
+        if inside {
+            return None;
+        }
+        let (mut enter, mut exit) = (0.0_f32, f32::INFINITY);
+        let mut normal = Vec2d::ZERO;
+        let axes = [
+            (
+                self.origin.x,
+                self.direction.x,
+                min.x,
+                max.x,
+                Vec2d::new(1.0, 0.0),
+            ),
+            (
+                self.origin.y,
+                self.direction.y,
+                min.y,
+                max.y,
+                Vec2d::new(0.0, 1.0),
+            ),
+        ];
+        for (origin, direction, low, high, axis) in axes {
+            if direction == 0.0 {
+                if origin < low || origin > high {
+                    return None;
+                }
+                continue;
+            }
+            let (near, far, side) = if direction > 0.0 {
+                (
+                    (low - origin) / direction,
+                    (high - origin) / direction,
+                    -axis,
+                )
+            } else {
+                (
+                    (high - origin) / direction,
+                    (low - origin) / direction,
+                    axis,
+                )
+            };
+            if near > enter {
+                enter = near;
+                normal = side;
+            }
+            exit = exit.min(far);
+        }
+        if enter > exit {
+            return None;
+        }
+        Some(RayHit {
+            distance: enter,
+            point: self.at(enter),
+            normal,
+        })
+    }
+
+    fn cast_convex(&self, convex: &Convex) -> Option<RayHit> {
+        if convex.contains(self.origin) {
+            return None;
+        }
+        let (mut enter, mut exit) = (0.0_f32, f32::INFINITY);
+        let mut normal = Vec2d::ZERO;
+        for (a, _, side) in convex.edges() {
+            let height = a.component(self.origin) * side;
+            let approach = self.direction * side;
+            if approach == 0.0 {
+                if height > 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let distance = -height / approach;
+            if approach < 0.0 {
+                if distance > enter {
+                    enter = distance;
+                    normal = side;
+                }
+            } else {
+                exit = exit.min(distance);
+            }
+        }
+        if enter > exit || normal == Vec2d::ZERO {
+            return None;
+        }
+        Some(RayHit {
+            distance: enter,
+            point: self.at(enter),
+            normal,
+        })
+    }
+
+    fn cast_heightfield(&self, field: &Heightfield) -> Option<RayHit> {
+        if field.contains(self.origin) {
+            return None;
+        }
+        // A vertical ray of infinite length reaches no farther along x than its origin.
+        let reach = self.direction.x * self.max_distance;
+        let (low, high) = match reach {
+            reach if reach.is_nan() => (self.origin.x, self.origin.x),
+            reach if reach < 0.0 => (self.origin.x + reach, self.origin.x),
+            reach => (self.origin.x, self.origin.x + reach),
+        };
+        field
+            .segments_between(low, high)
+            .filter_map(|index| {
+                let (a, b) = field.segment(index)?;
+                let normal = field.normal(index)?;
+                let approach = self.direction * normal;
+                if approach >= 0.0 {
+                    return None;
+                }
+                let distance = -(a.component(self.origin) * normal) / approach;
+                let point = self.at(distance);
+                (distance >= 0.0 && (a.x..=b.x).contains(&point.x)).then_some(RayHit {
+                    distance,
+                    point,
+                    normal,
+                })
+            })
+            .min_by(|hit1, hit2| hit1.distance.total_cmp(&hit2.distance))
+    }
+
+    /// Advances along the ray up to the line supporting the shape at its closest point, until
+    /// the ray touches the shape or turns out to miss it.
+    fn cast_support<S: Support + ?Sized>(&self, shape: &S) -> Option<RayHit> {
+        let mut gap = gjk::distance(&self.origin, shape)?;
+        let mut distance = 0.0;
+        for _ in 0..MAX_ADVANCES {
+            let normal = gap.point2.component(gap.point1) / gap.distance;
+            let approach = -(self.direction * normal);
+            if approach <= 0.0 {
+                return None;
+            }
+            distance += gap.distance / approach;
+            if distance > self.max_distance {
+                return None;
+            }
+            let point = self.at(distance);
+            match gjk::distance(&point, shape) {
+                Some(next) if next.distance > ADVANCE_TOLERANCE => gap = next,
+                _ => {
+                    return Some(RayHit {
+                        distance,
+                        point,
+                        normal,
+                    })
+                }
+            }
+        }
+        None
+    }
+}
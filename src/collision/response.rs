@@ -0,0 +1,180 @@
+use crate::{formulas::elastic_collision, obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::contact::Contact2d;
+
+/// Fraction of the penetration removed by [`separate`] on each call.
+const SEPARATION_PERCENT: f32 = 0.8;
+
+/// Penetration allowed by [`separate`], which keeps resting contacts touching between steps.
+const SEPARATION_SLOP: f32 = 0.01;
+
+/// Resolves an elastic collision between two objects, updating their velocities.
+///
+/// Dynamic objects exchange momentum with [`elastic_collision::calculate`]. Static and
+/// kinematic objects behave as if their mass were infinite: they are never pushed, and a
+/// dynamic object hitting them bounces off relative to their velocity. Static objects are
+/// treated as resting even if a velocity was set on them.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::response::resolve_elastic,
+/// #     obj::{body_type::BodyType, obj_2d::Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut ball = Object2dBuilder::new().mass(1.0).velocity(vec2(0.0, 5.0)).build();
+/// let mut floor = Object2dBuilder::new()
+///     .mass(1.0)
+///     .body_type(BodyType::Static)
+///     .build();
+///
+/// resolve_elastic(&mut ball, &mut floor);
+///
+/// assert_eq!(ball.velocity, vec2(0.0, -5.0));
+/// assert_eq!(floor.velocity, vec2(0.0, 0.0));
+/// ```
+///
+/// # Parameters
+///
+/// - `obj1`: The first object involved in the collision.
+/// - `obj2`: The second object involved in the collision.
+pub fn resolve_elastic(obj1: &mut Object2d, obj2: &mut Object2d) {
+    let v1 = surface_velocity(obj1);
+    let v2 = surface_velocity(obj2);
+    match (obj1.body_type.is_dynamic(), obj2.body_type.is_dynamic()) {
+        (true, true) => {
+            obj1.velocity = elastic_collision::calculate(v1, obj1.mass, obj2.mass, v2);
+            obj2.velocity = elastic_collision::calculate(v2, obj2.mass, obj1.mass, v1);
+        }
+        (true, false) => obj1.velocity = reflect(v1, v2),
+        (false, true) => obj2.velocity = reflect(v2, v1),
+        (false, false) => {}
+    }
+}
+
+/// Resolves a contact between two objects with restitution and Coulomb friction, updating their velocities.
+///
+/// An impulse along the normal stops the objects from moving into each other, bouncing them
+/// apart according to the restitution of their combined [`Material`](crate::obj::material::Material).
+/// Then a tangential friction impulse opposes their sliding: while it stays below the static
+/// friction coefficient times the normal impulse the surfaces stick, otherwise it is clamped to
+/// the kinetic friction coefficient times the normal impulse. Impulses acting away from the
+/// position of an object also spin it, so circles roll. Static and kinematic objects are never pushed.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::{response::resolve_contact, shape::Shape, Collision2d},
+/// #     obj::{body_type::BodyType, material::Material, obj_2d::Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let sticky = Material {
+///     restitution: 0.0,
+///     static_friction: 1.0,
+///     kinetic_friction: 1.0,
+/// };
+/// let mut crate_ = Object2dBuilder::new()
+///     .mass(1.0)
+///     .shape(Shape::AABB(vec2(-1.0, -1.0), vec2(1.0, 1.0)))
+///     .velocity(vec2(2.0, 4.0))
+///     .material(sticky)
+///     .build();
+/// let mut floor = Object2dBuilder::new()
+///     .position(vec2(0.0, 0.9))
+///     .shape(Shape::AABB(vec2(-10.0, 0.0), vec2(10.0, 1.0)))
+///     .body_type(BodyType::Static)
+///     .material(sticky)
+///     .build();
+///
+/// let contact = Collision2d::new(crate_.clone(), floor.clone()).contact().unwrap();
+/// resolve_contact(&mut crate_, &mut floor, &contact);
+///
+/// // The crate lands without bouncing and friction stops it from sliding.
+/// assert_eq!(crate_.velocity, vec2(0.0, 0.0));
+/// ```
+///
+/// # Parameters
+///
+/// - `obj1`: The first object involved in the collision.
+/// - `obj2`: The second object involved in the collision.
+/// - `contact`: The contact between both objects, its normal points from `obj1` towards `obj2`.
+pub fn resolve_contact(obj1: &mut Object2d, obj2: &mut Object2d, contact: &Contact2d) {
+    let r1 = obj1.vec.component(contact.point);
+    let r2 = obj2.vec.component(contact.point);
+    let normal = contact.normal;
+
+    let relative = obj2.point_velocity(r2) - obj1.point_velocity(r1);
+    let normal_speed = relative * normal;
+    if normal_speed > 0.0 {
+        return;
+    }
+    let normal_mass = obj1.inverse_mass_at(r1, normal) + obj2.inverse_mass_at(r2, normal);
+    if normal_mass <= 0.0 {
+        return;
+    }
+    let material = obj1.material.combine(&obj2.material);
+    let normal_impulse = -(1.0 + material.restitution) * normal_speed / normal_mass;
+    apply_impulse(obj1, obj2, r1, r2, normal * normal_impulse);
+
+    let relative = obj2.point_velocity(r2) - obj1.point_velocity(r1);
+    let sliding = relative - normal * (relative * normal);
+    let sliding_speed = sliding.magnitude();
+    if sliding_speed == 0.0 {
+        return;
+    }
+    let tangent = sliding / sliding_speed;
+    let tangent_mass = obj1.inverse_mass_at(r1, tangent) + obj2.inverse_mass_at(r2, tangent);
+    if tangent_mass <= 0.0 {
+        return;
+    }
+    let mut friction_impulse = sliding_speed / tangent_mass;
+    if friction_impulse > material.static_friction * normal_impulse {
+        friction_impulse = material.kinetic_friction * normal_impulse;
+    }
+    apply_impulse(obj1, obj2, r1, r2, -tangent * friction_impulse);
+}
+
+/// Moves two overlapping objects apart along the contact normal.
+///
+/// Most of the penetration is removed in proportion to the inverse masses of the objects, a small
+/// overlap is kept so resting objects stay in contact. Static and kinematic objects are never moved.
+///
+/// # Parameters
+///
+/// - `obj1`: The first object involved in the collision.
+/// - `obj2`: The second object involved in the collision.
+/// - `contact`: The contact between both objects, its normal points from `obj1` towards `obj2`.
+pub fn separate(obj1: &mut Object2d, obj2: &mut Object2d, contact: &Contact2d) {
+    let (inverse_mass1, inverse_mass2) = (obj1.inverse_mass(), obj2.inverse_mass());
+    let inverse_masses = inverse_mass1 + inverse_mass2;
+    if inverse_masses <= 0.0 {
+        return;
+    }
+    let depth = (contact.depth - SEPARATION_SLOP).max(0.0);
+    let correction = contact.normal * (SEPARATION_PERCENT * depth / inverse_masses);
+    obj1.vec -= correction * inverse_mass1;
+    obj2.vec += correction * inverse_mass2;
+}
+
+/// Applies `impulse` to `obj2` and its opposite to `obj1`, at offsets `r2` and `r1`.
+fn apply_impulse(obj1: &mut Object2d, obj2: &mut Object2d, r1: Vec2d, r2: Vec2d, impulse: Vec2d) {
+    obj1.apply_impulse_at(-impulse, r1);
+    obj2.apply_impulse_at(impulse, r2);
+}
+
+/// Velocity of a dynamic object after bouncing off an object of infinite mass,
+/// the limit of [`elastic_collision::calculate`] when the second mass grows without bound.
+fn reflect(v: Vec2d, wall: Vec2d) -> Vec2d {
+    Vec2d::new(2.0 * wall.x - v.x, 2.0 * wall.y - v.y)
+}
+
+/// The velocity an object takes part in a collision with.
+fn surface_velocity(obj: &Object2d) -> Vec2d {
+    if obj.body_type.is_static() {
+        Vec2d::new(0.0, 0.0)
+    } else {
+        obj.velocity
+    }
+}
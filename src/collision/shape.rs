@@ -1,22 +1,262 @@
+#[cfg(feature = "alloc")]
+use alloc::{sync::Arc, vec::Vec};
+use core::{fmt, ops::Deref};
+
 use crate::vec::vec_2d::Vec2d;
 
+use super::support::Support;
+
 /// Represents different shapes in a 2D space.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Shape {
     /// No shape defined.
     None,
     /// A circular shape.
     Circle,
-    /// An axis-aligned bounding box (AABB) defined by two vectors.
+    /// An axis-aligned bounding box (AABB) defined by its minimum and maximum corners,
+    /// relative to the position of the object. AABBs never rotate.
+    ///
+    /// Up to 0.1.6 the corners were in world coordinates and the position of the object was
+    /// ignored, an object at the origin keeps that meaning.
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{shape::Shape, Collision2d},
+    /// #     obj::obj_2d::{Object2d, Object2dBuilder},
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let crate_ = Object2dBuilder::new()
+    ///     .position(vec2(10.0, 10.0))
+    ///     .shape(Shape::AABB(vec2(-1.0, -1.0), vec2(1.0, 1.0)))
+    ///     .build();
+    /// let near = Object2d { vec: vec2(11.5, 10.0), ..crate_.clone() };
+    /// let far = Object2d { vec: vec2(13.0, 10.0), ..crate_.clone() };
+    ///
+    /// assert!(Collision2d::new(crate_.clone(), near).collider());
+    /// assert!(!Collision2d::new(crate_, far).collider());
+    /// ```
     AABB(Vec2d, Vec2d),
+    /// A half-plane, like an infinite wall or floor, bounded by the line through the position of
+    /// the object perpendicular to the given unit normal. Everything behind the line, opposite to
+    /// the normal, is solid. Half-planes never rotate.
+    HalfPlane(Vec2d),
+    /// Several child shapes at offsets from the position of the object, like an L-shaped piece
+    /// made of two boxes. The children turn with the object, AABB children are taken as
+    /// [`Shape::Rect`] so they turn too.
+    ///
+    /// The children are borrowed from a `static` or shared between the objects using them, see
+    /// [`Shared`].
+    Compound(Shared<ChildShape>),
+    /// A convex polygon given by its vertices relative to the position of the object, in either
+    /// winding order. Polygons turn with the object.
+    Polygon(&'static [Vec2d]),
+    /// A rectangle given by its minimum and maximum corners relative to the position of the
+    /// object, like an AABB that turns with the object.
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let plank = Object2dBuilder::new()
+    ///     .angle(core::f32::consts::FRAC_PI_2)
+    ///     .shape(Shape::Rect(vec2(-2.0, -0.5), vec2(2.0, 0.5)))
+    ///     .build();
+    ///
+    /// let (min, max) = plank.bounding_box().unwrap();
+    /// assert!((min - vec2(-0.5, -2.0)).magnitude() < 1e-5);
+    /// assert!((max - vec2(0.5, 2.0)).magnitude() < 1e-5);
+    /// ```
+    Rect(Vec2d, Vec2d),
+    /// A terrain profile of heights relative to the position of the object, sampled every
+    /// `spacing` along `x` from the position of the object, see
+    /// [`Heightfield`](super::heightfield::Heightfield). Everything below the profile is solid.
+    /// Heightfields never rotate and are meant for static objects.
+    ///
+    /// Like the children of compounds, the heights are borrowed from a `static` or shared, see
+    /// [`Shared`], so terrain generated or loaded at runtime is freed with the level:
+    ///
+    /// ```
+    /// # use motion::collision::shape::Shape;
+    /// let heights: Vec<f32> = (0..64).map(|i| (i as f32 * 0.1).sin()).collect();
+    /// let terrain = Shape::Heightfield(heights.into(), 0.5);
+    /// ```
+    Heightfield(Shared<f32>, f32),
+    /// A convex shape written by users, given by its support function relative to the position of
+    /// the object, see [`Support`]. Custom shapes turn with the object.
+    Custom(&'static dyn Support),
+}
+
+/// A child of a [`Shape::Compound`].
+///
+/// The mass of the object is shared among its children in proportion to their area.
+///
+/// # Fields
+///
+/// - `offset`: The position of the child relative to the position of the object.
+/// - `shape`: The shape of the child.
+/// - `radius`: The radius of the child, if it is a circle.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::shape::{ChildShape, Shape, Shared},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::Vec2d,
+/// # };
+/// static CAR: [ChildShape; 3] = [
+///     ChildShape::new(Vec2d::new(0.0, 0.0), Shape::AABB(Vec2d::new(-2.0, -0.5), Vec2d::new(2.0, 0.5))),
+///     ChildShape::circle(Vec2d::new(-1.5, 0.5), 0.5),
+///     ChildShape::circle(Vec2d::new(1.5, 0.5), 0.5),
+/// ];
+///
+/// let car = Object2dBuilder::new()
+///     .mass(10.0)
+///     .shape(Shape::Compound(Shared::from_static(&CAR)))
+///     .build();
+/// assert!((car.area() - (4.0 + core::f32::consts::FRAC_PI_2)).abs() < 1e-5);
+/// assert!(car.inverse_inertia() > 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChildShape {
+    pub offset: Vec2d,
+    pub shape: Shape,
+    pub radius: f32,
+}
+
+impl ChildShape {
+    /// Creates a new `ChildShape` without a radius.
+    #[must_use]
+    pub const fn new(offset: Vec2d, shape: Shape) -> Self {
+        Self {
+            offset,
+            shape,
+            radius: 0.0,
+        }
+    }
+
+    /// Creates a new circular `ChildShape`.
+    #[must_use]
+    pub const fn circle(offset: Vec2d, radius: f32) -> Self {
+        Self {
+            offset,
+            shape: Shape::Circle,
+            radius,
+        }
+    }
+}
+
+/// A slice of shape data, the children of a [`Shape::Compound`] or the heights of a
+/// [`Shape::Heightfield`], borrowed for the whole program or, with the `alloc` feature, shared with an [`Arc`] and freed with the last shape
+/// using it. Cloning a `Shared` never copies the slice.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::shape::{ChildShape, Shape, Shared},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::{vec2, Vec2d},
+/// # };
+/// static WHEEL: [ChildShape; 1] = [ChildShape::circle(Vec2d::ZERO, 1.0)];
+/// let wheel = Shape::Compound(Shared::from_static(&WHEEL));
+///
+/// // Children built at runtime are freed with the last object using them.
+/// let spokes: Vec<ChildShape> = (0..4)
+///     .map(|i| ChildShape::circle(vec2(i as f32, 0.0), 0.5))
+///     .collect();
+/// let rod = Object2dBuilder::new().mass(1.0).shape(Shape::Compound(spokes.into())).build();
+/// assert_eq!(rod.children().count(), 4);
+/// ```
+pub struct Shared<T: 'static>(Storage<T>);
+
+/// Where the items of a [`Shared`] are kept.
+enum Storage<T: 'static> {
+    Static(&'static [T]),
+    #[cfg(feature = "alloc")]
+    Arc(Arc<[T]>),
+}
+
+impl<T> Shared<T> {
+    /// Creates a new `Shared` borrowing `items` for the whole program.
+    #[must_use]
+    pub const fn from_static(items: &'static [T]) -> Self {
+        Self(Storage::Static(items))
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match &self.0 {
+            Storage::Static(items) => items,
+            #[cfg(feature = "alloc")]
+            Storage::Arc(items) => items,
+        }
+    }
+}
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        match &self.0 {
+            Storage::Static(items) => Self(Storage::Static(items)),
+            #[cfg(feature = "alloc")]
+            Storage::Arc(items) => Self(Storage::Arc(Arc::clone(items))),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Shared<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for Shared<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T> From<&'static [T]> for Shared<T> {
+    fn from(items: &'static [T]) -> Self {
+        Self::from_static(items)
+    }
+}
+
+impl<T, const N: usize> From<&'static [T; N]> for Shared<T> {
+    fn from(items: &'static [T; N]) -> Self {
+        Self::from_static(items)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Arc<[T]>> for Shared<T> {
+    fn from(items: Arc<[T]>) -> Self {
+        Self(Storage::Arc(items))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> From<Vec<T>> for Shared<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self(Storage::Arc(items.into()))
+    }
 }
 
 impl Shape {
+    /// Returns whether the shape is kept axis aligned, so objects with it never rotate, which is
+    /// only the case of AABBs.
+    #[must_use]
+    pub fn is_axis_aligned(&self) -> bool {
+        matches!(self, Shape::AABB(..))
+    }
+
     /// Retrieves the AABB (axis-aligned bounding box) vectors if the shape is an AABB.
     ///
     /// # Examples
     ///
     /// ```
+    /// # use motion::{collision::shape::Shape, vec::vec_2d::Vec2d};
     /// let shape = Shape::AABB(Vec2d::new(0.0, 0.0), Vec2d::new(1.0, 1.0));
     /// let (v1, v2) = shape.get_aabb();
     /// assert_eq!(v1, Vec2d::new(0.0, 0.0));
@@ -0,0 +1,220 @@
+use core::fmt::Debug;
+
+use crate::{collision::shape::Shape, obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{contact::Contact2d, polygon::Convex};
+
+/// How far the direction of [`face`] is turned both ways, in radians. Sides turned less than
+/// this from the direction count as facing it.
+const FACE_ANGLE: f32 = 1e-2;
+
+/// A convex shape described by its support function, which is all [`gjk`](super::gjk) and
+/// [`epa`](super::epa) need to find how two convex shapes touch.
+///
+/// Shapes written by users take part in collisions through [`Shape::Custom`], where the support
+/// function works in the coordinates of the object: relative to its position and before turning
+/// it by its angle.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::{shape::Shape, support::Support, Collision2d},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::{vec2, Vec2d},
+/// # };
+/// /// A segment of `2 * half_length` along `x` rounded by `radius`.
+/// #[derive(Debug)]
+/// struct Capsule {
+///     half_length: f32,
+///     radius: f32,
+/// }
+///
+/// impl Support for Capsule {
+///     fn support(&self, direction: Vec2d) -> Vec2d {
+///         let end = if direction.x < 0.0 { -self.half_length } else { self.half_length };
+///         vec2(end, 0.0) + direction * (self.radius / direction.magnitude())
+///     }
+/// }
+///
+/// static CAPSULE: Capsule = Capsule { half_length: 1.0, radius: 0.5 };
+///
+/// let capsule = Object2dBuilder::new().shape(Shape::Custom(&CAPSULE)).build();
+/// let ball = Object2dBuilder::new()
+///     .position(vec2(2.0, 0.0))
+///     .radius(1.0)
+///     .shape(Shape::Circle)
+///     .build();
+///
+/// let contact = Collision2d::new(capsule, ball).contact().unwrap();
+/// assert!((contact.normal - vec2(1.0, 0.0)).magnitude() < 1e-3);
+/// assert!((contact.depth - 0.5).abs() < 1e-3);
+/// ```
+pub trait Support: Debug + Sync {
+    /// Returns the point of the shape farthest along `direction`, which is never zero.
+    fn support(&self, direction: Vec2d) -> Vec2d;
+
+    /// Returns the area of the shape, see [`Object2d::area`]. Defaults to `0.0`.
+    fn area(&self) -> f32 {
+        0.0
+    }
+
+    /// Returns the moment of inertia of the shape for a unit mass around the origin of its
+    /// coordinates, the default of `0.0` keeps objects with this shape from rotating.
+    fn unit_inertia(&self) -> f32 {
+        0.0
+    }
+}
+
+/// Shapes are compared by identity, so [`Shape::Custom`] can be compared like the other shapes.
+impl PartialEq for dyn Support {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::addr_eq(self, other)
+    }
+}
+
+/// A single point, the smallest convex shape.
+impl Support for Vec2d {
+    fn support(&self, _direction: Vec2d) -> Vec2d {
+        *self
+    }
+}
+
+/// A circle in world coordinates.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::support::{Circle, Support}, vec::vec_2d::vec2};
+/// let circle = Circle::new(vec2(1.0, 1.0), 2.0);
+/// assert_eq!(circle.support(vec2(0.0, -5.0)), vec2(1.0, -1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle {
+    /// The center of the circle.
+    pub center: Vec2d,
+    /// The radius of the circle.
+    pub radius: f32,
+}
+
+impl Circle {
+    /// Creates a new `Circle`.
+    #[must_use]
+    pub fn new(center: Vec2d, radius: f32) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl Support for Circle {
+    fn support(&self, direction: Vec2d) -> Vec2d {
+        self.center + direction * (self.radius / direction.magnitude())
+    }
+}
+
+impl Support for Convex {
+    fn support(&self, direction: Vec2d) -> Vec2d {
+        self.vertices()
+            .max_by(|a, b| (*a * direction).total_cmp(&(*b * direction)))
+            .unwrap_or_default()
+    }
+}
+
+/// The convex shape of an object in world coordinates.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Placed {
+    Point(Vec2d),
+    Circle(Circle),
+    Polygon(Convex),
+    Custom {
+        shape: &'static dyn Support,
+        position: Vec2d,
+        angle: f32,
+    },
+}
+
+impl Placed {
+    /// Returns the convex shape of an object, or `None` if its shape is not convex.
+    pub(crate) fn of(obj: &Object2d) -> Option<Self> {
+        match obj.shape {
+            Shape::None => Some(Placed::Point(obj.vec)),
+            Shape::Circle => Some(Placed::Circle(Circle::new(obj.vec, obj.radius))),
+            Shape::AABB(..) | Shape::Rect(..) | Shape::Polygon(_) => {
+                Convex::of(obj).map(Placed::Polygon)
+            }
+            Shape::Custom(shape) => Some(Placed::Custom {
+                shape,
+                position: obj.vec,
+                angle: obj.angle,
+            }),
+            Shape::HalfPlane(_) | Shape::Compound(_) | Shape::Heightfield(..) => None,
+        }
+    }
+}
+
+impl Support for Placed {
+    fn support(&self, direction: Vec2d) -> Vec2d {
+        match self {
+            Placed::Point(point) => *point,
+            Placed::Circle(circle) => circle.support(direction),
+            Placed::Polygon(convex) => convex.support(direction),
+            Placed::Custom {
+                shape,
+                position,
+                angle,
+            } => *position + shape.support(direction.rotated(-angle)).rotated(*angle),
+        }
+    }
+}
+
+/// Computes the contact between a convex shape and a half-plane through `point` with the unit
+/// `normal`, the normal of the contact points from the shape towards the half-plane.
+///
+/// The point of the contact is the average of the ends of the side of the shape facing the
+/// half-plane that lie behind it, see [`convex_half_plane`](super::polygon::convex_half_plane).
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{collision::{polygon::Convex, support::support_half_plane}, vec::vec_2d::vec2};
+/// let crate_ = Convex::aabb(vec2(0.0, -1.0), vec2(2.0, 0.5));
+///
+/// let contact = support_half_plane(&crate_, vec2(0.0, 0.0), vec2(0.0, -1.0)).unwrap();
+/// assert_eq!(contact.normal, vec2(0.0, 1.0));
+/// assert_eq!(contact.depth, 0.5);
+/// assert_eq!(contact.point, vec2(1.0, 0.5));
+/// ```
+#[must_use]
+pub fn support_half_plane<S: Support + ?Sized>(
+    shape: &S,
+    point: Vec2d,
+    normal: Vec2d,
+) -> Option<Contact2d> {
+    let (first, last) = face(shape, -normal);
+    let (mut depth, mut sum, mut count) = (0.0_f32, Vec2d::ZERO, 0.0);
+    for end in [first, last] {
+        let behind = -(point.component(end) * normal);
+        if behind > 0.0 {
+            depth = depth.max(behind);
+            sum += end;
+            count += 1.0;
+        }
+    }
+    if depth <= 0.0 {
+        return None;
+    }
+    Some(Contact2d {
+        normal: -normal,
+        depth,
+        point: sum / count,
+    })
+}
+
+/// Returns the ends of the side of a shape facing `direction`, ordered along
+/// `direction.perp()`. Both ends are the same point at a corner, and lie close together on
+/// curved outlines.
+pub(crate) fn face<S: Support + ?Sized>(shape: &S, direction: Vec2d) -> (Vec2d, Vec2d) {
+    (
+        shape.support(direction.rotated(-FACE_ANGLE)),
+        shape.support(direction.rotated(FACE_ANGLE)),
+    )
+}
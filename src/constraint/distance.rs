@@ -0,0 +1,120 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{anchor_offset, apply_correction, Constraint};
+
+/// A rigid rod keeping two anchors at a fixed distance, for pendulums, chains and ragdoll limbs.
+///
+/// The anchors are given in the local frame of each object, relative to its position and
+/// rotated with it.
+///
+/// # Fields
+///
+/// - `anchor1`: The anchor on the first object.
+/// - `anchor2`: The anchor on the second object.
+/// - `length`: The distance kept between both anchors.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     constraint::distance::DistanceJoint,
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2dBuilder,
+/// # };
+/// let mut world = World2dBuilder::new().gravity(vec2(0.0, 9.807)).build();
+/// let bob = world.add_body(Object2dBuilder::new().mass(1.0).position(vec2(3.0, 0.0)).build());
+/// // Without a second body the anchor is a point of the world.
+/// world.add_joint(bob, None, DistanceJoint::new(vec2(0.0, 0.0), vec2(0.0, 0.0), 3.0));
+///
+/// for _ in 0..200 {
+///     world.step(1.0 / 60.0);
+/// }
+///
+/// let bob = world.body(bob).unwrap();
+/// assert!((bob.vec.magnitude() - 3.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceJoint {
+    pub anchor1: Vec2d,
+    pub anchor2: Vec2d,
+    pub length: f32,
+    r1: Vec2d,
+    r2: Vec2d,
+    normal: Vec2d,
+    mass: f32,
+    impulse: f32,
+}
+
+impl DistanceJoint {
+    /// Creates a new `DistanceJoint` between two local anchors.
+    #[must_use]
+    pub fn new(anchor1: Vec2d, anchor2: Vec2d, length: f32) -> Self {
+        Self {
+            anchor1,
+            anchor2,
+            length,
+            r1: Vec2d::ZERO,
+            r2: Vec2d::ZERO,
+            normal: Vec2d::ZERO,
+            mass: 0.0,
+            impulse: 0.0,
+        }
+    }
+
+    /// Returns the offsets of both anchors and the unit vector and distance from the first anchor
+    /// to the second.
+    fn geometry(&self, obj1: &Object2d, obj2: &Object2d) -> (Vec2d, Vec2d, Vec2d, f32) {
+        let r1 = anchor_offset(obj1, self.anchor1);
+        let r2 = anchor_offset(obj2, self.anchor2);
+        let delta = (obj2.vec + r2) - (obj1.vec + r1);
+        let distance = delta.magnitude();
+        let normal = if distance > 0.0 {
+            delta / distance
+        } else {
+            Vec2d::ZERO
+        };
+        (r1, r2, normal, distance)
+    }
+}
+
+impl Constraint for DistanceJoint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, _delta_time: f32) {
+        let (r1, r2, normal, _) = self.geometry(obj1, obj2);
+        let inverse_mass = obj1.inverse_mass_at(r1, normal) + obj2.inverse_mass_at(r2, normal);
+        self.r1 = r1;
+        self.r2 = r2;
+        self.normal = normal;
+        self.mass = if inverse_mass > 0.0 {
+            1.0 / inverse_mass
+        } else {
+            0.0
+        };
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let impulse = self.normal * self.impulse;
+        obj1.apply_impulse_at(-impulse, self.r1);
+        obj2.apply_impulse_at(impulse, self.r2);
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let relative = obj2.point_velocity(self.r2) - obj1.point_velocity(self.r1);
+        let lambda = -(relative * self.normal) * self.mass;
+        self.impulse += lambda;
+        let impulse = self.normal * lambda;
+        obj1.apply_impulse_at(-impulse, self.r1);
+        obj2.apply_impulse_at(impulse, self.r2);
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let (r1, r2, normal, distance) = self.geometry(obj1, obj2);
+        let inverse_mass = obj1.inverse_mass_at(r1, normal) + obj2.inverse_mass_at(r2, normal);
+        if inverse_mass <= 0.0 {
+            return;
+        }
+        let correction = normal * (-(distance - self.length) / inverse_mass);
+        apply_correction(obj1, -correction, r1);
+        apply_correction(obj2, correction, r2);
+    }
+}
@@ -0,0 +1,377 @@
+pub mod distance;
+pub mod mouse;
+pub mod prismatic;
+pub mod pulley;
+pub mod revolute;
+pub mod weld;
+
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use self::{
+    distance::DistanceJoint, mouse::MouseJoint, prismatic::PrismaticJoint, pulley::PulleyJoint,
+    revolute::RevoluteJoint, weld::WeldJoint,
+};
+
+/// The number of solver iterations used by default.
+pub const DEFAULT_SOLVER_ITERATIONS: usize = 8;
+
+/// Drives the relative motion of the objects of a joint at a target speed.
+///
+/// # Fields
+///
+/// - `speed`: The target relative speed, in radians per second for rotations or units per
+///   second for translations.
+/// - `max_force`: The largest torque, or force for translations, the motor can exert.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Motor {
+    pub speed: f32,
+    pub max_force: f32,
+}
+
+/// A trait for constraints between two 2D objects, like joints.
+///
+/// Constraints are solved after the objects have been integrated. Each step,
+/// [`Constraint::prepare`] and [`Constraint::warm_start`] are called once, then
+/// [`Constraint::solve_velocity`] is called a number of times to remove the relative velocity
+/// that breaks the constraint, and finally [`Constraint::solve_position`] is called the same
+/// number of times to remove the error accumulated in the positions. More iterations give
+/// stiffer constraints when several of them act on the same objects, like in a chain.
+///
+/// Constraints solved with sequential impulses accumulate the impulse they apply and keep it
+/// between steps. Applying it again in `warm_start` lets the solver start from the previous
+/// solution, which is what keeps stacks and chains at rest.
+///
+/// The second object of a constraint may be a static object standing for the world, in which
+/// case its anchor is a point of the world.
+///
+/// # Examples
+///
+/// Solving a constraint without a world:
+///
+/// ```
+/// # use motion::{
+/// #     constraint::{distance::DistanceJoint, solve},
+/// #     obj::{body_type::BodyType, obj_2d::Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut pivot = Object2dBuilder::new().body_type(BodyType::Static).build();
+/// let mut bob = Object2dBuilder::new().mass(1.0).position(vec2(2.0, 0.0)).build();
+/// let mut rod = DistanceJoint::new(vec2(0.0, 0.0), vec2(0.0, 0.0), 2.0);
+///
+/// for _ in 0..100 {
+///     bob.add_force(vec2(0.0, 9.807));
+///     bob.integrate(0.01);
+///     solve(&mut rod, &mut pivot, &mut bob, 0.01, 8);
+/// }
+///
+/// assert!((bob.vec.magnitude() - 2.0).abs() < 1e-3);
+/// assert!(bob.vec.y > 0.0);
+/// ```
+pub trait Constraint {
+    /// Prepares the constraint for a step of `delta_time` seconds, usually caching what does not
+    /// change between iterations.
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, delta_time: f32);
+
+    /// Applies the impulses accumulated during the previous step again, does nothing by default.
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let _ = (obj1, obj2);
+    }
+
+    /// Applies impulses to both objects so their relative velocity satisfies the constraint.
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d);
+
+    /// Moves both objects to reduce the error of the constraint.
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d);
+}
+
+/// Every joint supported by a [`World2d`](crate::world::World2d).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Joint {
+    /// See [`DistanceJoint`].
+    Distance(DistanceJoint),
+    /// See [`MouseJoint`].
+    Mouse(MouseJoint),
+    /// See [`PrismaticJoint`].
+    Prismatic(PrismaticJoint),
+    /// See [`PulleyJoint`].
+    Pulley(PulleyJoint),
+    /// See [`RevoluteJoint`].
+    Revolute(RevoluteJoint),
+    /// See [`WeldJoint`].
+    Weld(WeldJoint),
+}
+
+impl Constraint for Joint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, delta_time: f32) {
+        match self {
+            Joint::Distance(joint) => joint.prepare(obj1, obj2, delta_time),
+            Joint::Mouse(joint) => joint.prepare(obj1, obj2, delta_time),
+            Joint::Prismatic(joint) => joint.prepare(obj1, obj2, delta_time),
+            Joint::Pulley(joint) => joint.prepare(obj1, obj2, delta_time),
+            Joint::Revolute(joint) => joint.prepare(obj1, obj2, delta_time),
+            Joint::Weld(joint) => joint.prepare(obj1, obj2, delta_time),
+        }
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        match self {
+            Joint::Distance(joint) => joint.warm_start(obj1, obj2),
+            Joint::Mouse(joint) => joint.warm_start(obj1, obj2),
+            Joint::Prismatic(joint) => joint.warm_start(obj1, obj2),
+            Joint::Pulley(joint) => joint.warm_start(obj1, obj2),
+            Joint::Revolute(joint) => joint.warm_start(obj1, obj2),
+            Joint::Weld(joint) => joint.warm_start(obj1, obj2),
+        }
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        match self {
+            Joint::Distance(joint) => joint.solve_velocity(obj1, obj2),
+            Joint::Mouse(joint) => joint.solve_velocity(obj1, obj2),
+            Joint::Prismatic(joint) => joint.solve_velocity(obj1, obj2),
+            Joint::Pulley(joint) => joint.solve_velocity(obj1, obj2),
+            Joint::Revolute(joint) => joint.solve_velocity(obj1, obj2),
+            Joint::Weld(joint) => joint.solve_velocity(obj1, obj2),
+        }
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        match self {
+            Joint::Distance(joint) => joint.solve_position(obj1, obj2),
+            Joint::Mouse(joint) => joint.solve_position(obj1, obj2),
+            Joint::Prismatic(joint) => joint.solve_position(obj1, obj2),
+            Joint::Pulley(joint) => joint.solve_position(obj1, obj2),
+            Joint::Revolute(joint) => joint.solve_position(obj1, obj2),
+            Joint::Weld(joint) => joint.solve_position(obj1, obj2),
+        }
+    }
+}
+
+impl From<DistanceJoint> for Joint {
+    fn from(joint: DistanceJoint) -> Self {
+        Joint::Distance(joint)
+    }
+}
+
+impl From<MouseJoint> for Joint {
+    fn from(joint: MouseJoint) -> Self {
+        Joint::Mouse(joint)
+    }
+}
+
+impl From<PrismaticJoint> for Joint {
+    fn from(joint: PrismaticJoint) -> Self {
+        Joint::Prismatic(joint)
+    }
+}
+
+impl From<PulleyJoint> for Joint {
+    fn from(joint: PulleyJoint) -> Self {
+        Joint::Pulley(joint)
+    }
+}
+
+impl From<RevoluteJoint> for Joint {
+    fn from(joint: RevoluteJoint) -> Self {
+        Joint::Revolute(joint)
+    }
+}
+
+impl From<WeldJoint> for Joint {
+    fn from(joint: WeldJoint) -> Self {
+        Joint::Weld(joint)
+    }
+}
+
+/// Solves a single constraint between two objects, see [`Constraint`].
+///
+/// # Parameters
+///
+/// - `constraint`: The constraint to solve.
+/// - `obj1`: The first object of the constraint.
+/// - `obj2`: The second object of the constraint.
+/// - `delta_time`: The time step the objects were just advanced by.
+/// - `iterations`: The number of velocity and position iterations.
+pub fn solve<C: Constraint>(
+    constraint: &mut C,
+    obj1: &mut Object2d,
+    obj2: &mut Object2d,
+    delta_time: f32,
+    iterations: usize,
+) {
+    constraint.prepare(obj1, obj2, delta_time);
+    constraint.warm_start(obj1, obj2);
+    for _ in 0..iterations {
+        constraint.solve_velocity(obj1, obj2);
+    }
+    for _ in 0..iterations {
+        constraint.solve_position(obj1, obj2);
+    }
+}
+
+/// Returns the offset from the position of `obj` to the anchor given in its local frame.
+pub(crate) fn anchor_offset(obj: &Object2d, anchor: Vec2d) -> Vec2d {
+    anchor.rotated(obj.angle)
+}
+
+/// Applies an angular impulse to `obj2` and its opposite to `obj1`.
+pub(crate) fn apply_angular_impulse(obj1: &mut Object2d, obj2: &mut Object2d, impulse: f32) {
+    obj1.angular_velocity -= impulse * obj1.inverse_inertia();
+    obj2.angular_velocity += impulse * obj2.inverse_inertia();
+}
+
+/// Turns `obj2` by the angular correction and `obj1` by its opposite, weighted by their inverse
+/// inertia.
+pub(crate) fn apply_angular_correction(obj1: &mut Object2d, obj2: &mut Object2d, correction: f32) {
+    obj1.angle -= correction * obj1.inverse_inertia();
+    obj2.angle += correction * obj2.inverse_inertia();
+}
+
+/// Returns the inverse of the sum of the inverse inertia of both objects, or zero if neither
+/// can rotate.
+pub(crate) fn angular_mass(obj1: &Object2d, obj2: &Object2d) -> f32 {
+    let inverse_inertia = obj1.inverse_inertia() + obj2.inverse_inertia();
+    if inverse_inertia > 0.0 {
+        1.0 / inverse_inertia
+    } else {
+        0.0
+    }
+}
+
+/// The effective mass matrix of a constraint keeping two anchors at the same point.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct PointMass {
+    k11: f32,
+    k12: f32,
+    k22: f32,
+}
+
+impl PointMass {
+    /// Computes the matrix for two objects with anchors at offsets `r1` and `r2`.
+    pub(crate) fn new(obj1: &Object2d, obj2: &Object2d, r1: Vec2d, r2: Vec2d) -> Self {
+        let (m1, m2) = (obj1.inverse_mass(), obj2.inverse_mass());
+        let (i1, i2) = (obj1.inverse_inertia(), obj2.inverse_inertia());
+        Self {
+            k11: m1 + m2 + i1 * r1.y * r1.y + i2 * r2.y * r2.y,
+            k12: -i1 * r1.x * r1.y - i2 * r2.x * r2.y,
+            k22: m1 + m2 + i1 * r1.x * r1.x + i2 * r2.x * r2.x,
+        }
+    }
+
+    /// Computes the matrix for a single object with an anchor at offset `r`, held to a fixed point.
+    pub(crate) fn single(obj: &Object2d, r: Vec2d) -> Self {
+        let (m, i) = (obj.inverse_mass(), obj.inverse_inertia());
+        Self {
+            k11: m + i * r.y * r.y,
+            k12: -i * r.x * r.y,
+            k22: m + i * r.x * r.x,
+        }
+    }
+
+    /// Returns the matrix of a soft constraint, which lets the anchors drift apart by `softness`
+    /// for each unit of impulse.
+    pub(crate) fn softened(self, softness: f32) -> Self {
+        Self {
+            k11: self.k11 + softness,
+            k22: self.k22 + softness,
+            ..self
+        }
+    }
+
+    /// Returns the impulse that changes the relative velocity of the anchors by `change`.
+    pub(crate) fn solve(&self, change: Vec2d) -> Vec2d {
+        let determinant = self.k11 * self.k22 - self.k12 * self.k12;
+        if determinant == 0.0 {
+            return Vec2d::ZERO;
+        }
+        Vec2d::new(
+            self.k22 * change.x - self.k12 * change.y,
+            self.k11 * change.y - self.k12 * change.x,
+        ) / determinant
+    }
+}
+
+/// Applies impulses keeping the anchors at offsets `r1` and `r2` moving together.
+///
+/// # Returns
+///
+/// The impulse applied to `obj2`, its opposite was applied to `obj1`.
+pub(crate) fn solve_point_velocity(
+    obj1: &mut Object2d,
+    obj2: &mut Object2d,
+    r1: Vec2d,
+    r2: Vec2d,
+    mass: &PointMass,
+) -> Vec2d {
+    let relative = obj2.point_velocity(r2) - obj1.point_velocity(r1);
+    let impulse = mass.solve(-relative);
+    obj1.apply_impulse_at(-impulse, r1);
+    obj2.apply_impulse_at(impulse, r2);
+    impulse
+}
+
+/// Moves both objects so the local anchors `anchor1` and `anchor2` meet.
+pub(crate) fn solve_point_position(
+    obj1: &mut Object2d,
+    obj2: &mut Object2d,
+    anchor1: Vec2d,
+    anchor2: Vec2d,
+) {
+    let r1 = anchor_offset(obj1, anchor1);
+    let r2 = anchor_offset(obj2, anchor2);
+    let error = (obj2.vec + r2) - (obj1.vec + r1);
+    let correction = PointMass::new(obj1, obj2, r1, r2).solve(-error);
+    apply_correction(obj1, -correction, r1);
+    apply_correction(obj2, correction, r2);
+}
+
+/// Applies an impulse along `direction` to `obj2` and its opposite to `obj1`, where `arm1` and
+/// `arm2` are the torque arms turning each object.
+pub(crate) fn apply_axial_impulse(
+    obj1: &mut Object2d,
+    obj2: &mut Object2d,
+    direction: Vec2d,
+    (arm1, arm2): (f32, f32),
+    impulse: f32,
+) {
+    obj1.velocity -= direction * (impulse * obj1.inverse_mass());
+    obj1.angular_velocity -= arm1 * impulse * obj1.inverse_inertia();
+    obj2.velocity += direction * (impulse * obj2.inverse_mass());
+    obj2.angular_velocity += arm2 * impulse * obj2.inverse_inertia();
+}
+
+/// Moves `obj2` along `direction` and `obj1` the opposite way, turning them by the torque arms
+/// `arm1` and `arm2`, as if the positional impulse `correction` acted on them.
+pub(crate) fn apply_axial_correction(
+    obj1: &mut Object2d,
+    obj2: &mut Object2d,
+    direction: Vec2d,
+    (arm1, arm2): (f32, f32),
+    correction: f32,
+) {
+    obj1.vec -= direction * (correction * obj1.inverse_mass());
+    obj1.angle -= arm1 * correction * obj1.inverse_inertia();
+    obj2.vec += direction * (correction * obj2.inverse_mass());
+    obj2.angle += arm2 * correction * obj2.inverse_inertia();
+}
+
+/// Returns the inverse of the mass both objects oppose to an impulse along `direction` with the
+/// torque arms `arm1` and `arm2`, or zero if they cannot move that way.
+pub(crate) fn axial_mass(obj1: &Object2d, obj2: &Object2d, (arm1, arm2): (f32, f32)) -> f32 {
+    let inverse_mass = obj1.inverse_mass()
+        + obj2.inverse_mass()
+        + arm1 * arm1 * obj1.inverse_inertia()
+        + arm2 * arm2 * obj2.inverse_inertia();
+    if inverse_mass > 0.0 {
+        1.0 / inverse_mass
+    } else {
+        0.0
+    }
+}
+
+/// Moves `obj` and turns it as if the positional impulse `correction` acted on the point at
+/// offset `r` from its position. Only dynamic objects are moved.
+pub(crate) fn apply_correction(obj: &mut Object2d, correction: Vec2d, r: Vec2d) {
+    obj.vec += correction * obj.inverse_mass();
+    obj.angle += r.cross(correction) * obj.inverse_inertia();
+}
@@ -0,0 +1,143 @@
+use core::f32::consts::TAU;
+
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{anchor_offset, Constraint, PointMass};
+
+/// The frequency of the spring of a [`MouseJoint`] created with [`MouseJoint::new`], in hertz.
+pub const DEFAULT_MOUSE_FREQUENCY: f32 = 5.0;
+
+/// The damping ratio of the spring of a [`MouseJoint`] created with [`MouseJoint::new`].
+pub const DEFAULT_MOUSE_DAMPING_RATIO: f32 = 0.7;
+
+/// A soft spring pulling an anchor of an object towards a target point, for dragging objects
+/// with the mouse.
+///
+/// The spring is a damped oscillator with the given `frequency` and `damping_ratio` for the mass
+/// of the object, and it never pulls harder than `max_force`. The object is only moved through
+/// impulses, never teleported, so it still collides with the objects in its way. Only the first
+/// object of the joint is pulled, the second one is ignored.
+///
+/// # Fields
+///
+/// - `anchor`: The anchor on the object.
+/// - `target`: The point the anchor is pulled towards, in world coordinates.
+/// - `max_force`: The largest force the spring exerts.
+/// - `frequency`: The frequency of the spring in hertz, higher is stiffer.
+/// - `damping_ratio`: The damping ratio of the spring, `1.0` reaches the target without overshooting.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     constraint::{mouse::MouseJoint, Joint},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2d,
+/// # };
+/// let mut world = World2d::new();
+/// let crate_ = world.add_body(Object2dBuilder::new().mass(2.0).build());
+/// let mouse = world.add_joint(crate_, None, MouseJoint::new(vec2(0.0, 0.0), vec2(0.0, 0.0), 1000.0));
+///
+/// if let Some(Joint::Mouse(mouse)) = world.joint_mut(mouse) {
+///     mouse.target = vec2(4.0, 3.0);
+/// }
+/// world.step(1.0 / 60.0);
+/// // The crate starts moving towards the target instead of jumping to it.
+/// let crate_body = world.body(crate_).unwrap();
+/// assert_eq!(crate_body.vec, vec2(0.0, 0.0));
+/// assert!(crate_body.velocity.x > 0.0 && crate_body.velocity.y > 0.0);
+///
+/// for _ in 0..120 {
+///     world.step(1.0 / 60.0);
+/// }
+/// assert!(world.body(crate_).unwrap().vec.distance(vec2(4.0, 3.0)) < 1e-2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MouseJoint {
+    pub anchor: Vec2d,
+    pub target: Vec2d,
+    pub max_force: f32,
+    pub frequency: f32,
+    pub damping_ratio: f32,
+    r: Vec2d,
+    mass: PointMass,
+    softness: f32,
+    bias: Vec2d,
+    max_impulse: f32,
+    impulse: Vec2d,
+}
+
+impl MouseJoint {
+    /// Creates a new `MouseJoint` with the default spring.
+    #[must_use]
+    pub fn new(anchor: Vec2d, target: Vec2d, max_force: f32) -> Self {
+        Self {
+            anchor,
+            target,
+            max_force,
+            frequency: DEFAULT_MOUSE_FREQUENCY,
+            damping_ratio: DEFAULT_MOUSE_DAMPING_RATIO,
+            r: Vec2d::ZERO,
+            mass: PointMass::default(),
+            softness: 0.0,
+            bias: Vec2d::ZERO,
+            max_impulse: 0.0,
+            impulse: Vec2d::ZERO,
+        }
+    }
+
+    /// Sets the frequency in hertz and the damping ratio of the spring.
+    #[must_use]
+    pub fn with_spring(mut self, frequency: f32, damping_ratio: f32) -> Self {
+        self.frequency = frequency;
+        self.damping_ratio = damping_ratio;
+        self
+    }
+}
+
+impl Constraint for MouseJoint {
+    fn prepare(&mut self, obj1: &Object2d, _obj2: &Object2d, delta_time: f32) {
+        self.r = anchor_offset(obj1, self.anchor);
+        self.max_impulse = self.max_force * delta_time;
+        let magnitude = self.impulse.magnitude();
+        if magnitude > self.max_impulse {
+            self.impulse = self.impulse * (self.max_impulse / magnitude);
+        }
+
+        // The spring and damper are turned into the softness and bias of the constraint, so it
+        // behaves like an implicitly integrated spring.
+        let omega = TAU * self.frequency;
+        let stiffness = obj1.mass * omega * omega;
+        let damping = 2.0 * obj1.mass * self.damping_ratio * omega;
+        let softness = delta_time * (damping + delta_time * stiffness);
+        self.softness = if softness > 0.0 { 1.0 / softness } else { 0.0 };
+        let error = obj1.vec + self.r - self.target;
+        self.bias = error * (delta_time * stiffness * self.softness);
+
+        self.mass = PointMass::single(obj1, self.r).softened(self.softness);
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, _obj2: &mut Object2d) {
+        if obj1.body_type.is_dynamic() {
+            obj1.apply_impulse_at(self.impulse, self.r);
+        }
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, _obj2: &mut Object2d) {
+        if !obj1.body_type.is_dynamic() {
+            return;
+        }
+        let velocity = obj1.point_velocity(self.r);
+        let change = -(velocity + self.bias + self.impulse * self.softness);
+        let previous = self.impulse;
+        self.impulse += self.mass.solve(change);
+        let magnitude = self.impulse.magnitude();
+        if magnitude > self.max_impulse {
+            self.impulse = self.impulse * (self.max_impulse / magnitude);
+        }
+        obj1.apply_impulse_at(self.impulse - previous, self.r);
+    }
+
+    fn solve_position(&mut self, _obj1: &mut Object2d, _obj2: &mut Object2d) {}
+}
@@ -0,0 +1,247 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{
+    anchor_offset, angular_mass, apply_angular_correction, apply_angular_impulse,
+    apply_axial_correction, apply_axial_impulse, axial_mass, Constraint, Motor,
+};
+
+/// A slider letting two objects move only along an axis, for elevators on rails and pistons.
+///
+/// The axis is a unit vector given in the local frame of the first object and the relative angle
+/// of the objects is kept fixed. The translation of the joint is the distance from the first
+/// anchor to the second one along the axis, it can be kept within limits and driven by a
+/// [`Motor`].
+///
+/// # Fields
+///
+/// - `anchor1`: The anchor on the first object.
+/// - `anchor2`: The anchor on the second object.
+/// - `axis`: The unit axis of the joint, in the local frame of the first object.
+/// - `reference_angle`: The relative angle kept between the objects.
+/// - `limits`: The optional lower and upper translation.
+/// - `motor`: The optional motor driving the joint.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     constraint::prismatic::PrismaticJoint,
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2dBuilder,
+/// # };
+/// let mut world = World2dBuilder::new().gravity(vec2(0.0, 9.807)).build();
+/// let elevator = world.add_body(Object2dBuilder::new().mass(1.0).velocity(vec2(3.0, 0.0)).build());
+/// // A vertical rail through the origin. The world is the second object, so going down the
+/// // rail is a negative translation.
+/// let rail = PrismaticJoint::new(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 1.0)).with_limits(-2.0, 0.0);
+/// world.add_joint(elevator, None, rail);
+///
+/// for _ in 0..120 {
+///     world.step(1.0 / 60.0);
+/// }
+///
+/// let elevator = world.body(elevator).unwrap();
+/// assert!(elevator.vec.x.abs() < 1e-3);
+/// assert!((elevator.vec.y - 2.0).abs() < 1e-2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrismaticJoint {
+    pub anchor1: Vec2d,
+    pub anchor2: Vec2d,
+    pub axis: Vec2d,
+    pub reference_angle: f32,
+    pub limits: Option<(f32, f32)>,
+    pub motor: Option<Motor>,
+    axis_world: Vec2d,
+    axial_arms: (f32, f32),
+    perpendicular_arms: (f32, f32),
+    axial_mass: f32,
+    perpendicular_mass: f32,
+    angular_mass: f32,
+    translation: f32,
+    inverse_delta_time: f32,
+    max_motor_impulse: f32,
+    motor_impulse: f32,
+    lower_impulse: f32,
+    upper_impulse: f32,
+    perpendicular_impulse: f32,
+    angular_impulse: f32,
+}
+
+impl PrismaticJoint {
+    /// Creates a new free `PrismaticJoint` between two local anchors sliding along `axis`.
+    #[must_use]
+    pub fn new(anchor1: Vec2d, anchor2: Vec2d, axis: Vec2d) -> Self {
+        Self {
+            anchor1,
+            anchor2,
+            axis,
+            reference_angle: 0.0,
+            limits: None,
+            motor: None,
+            axis_world: Vec2d::ZERO,
+            axial_arms: (0.0, 0.0),
+            perpendicular_arms: (0.0, 0.0),
+            axial_mass: 0.0,
+            perpendicular_mass: 0.0,
+            angular_mass: 0.0,
+            translation: 0.0,
+            inverse_delta_time: 0.0,
+            max_motor_impulse: 0.0,
+            motor_impulse: 0.0,
+            lower_impulse: 0.0,
+            upper_impulse: 0.0,
+            perpendicular_impulse: 0.0,
+            angular_impulse: 0.0,
+        }
+    }
+
+    /// Sets the relative angle kept between the objects.
+    #[must_use]
+    pub fn with_reference_angle(mut self, reference_angle: f32) -> Self {
+        self.reference_angle = reference_angle;
+        self
+    }
+
+    /// Keeps the translation between `lower` and `upper`.
+    #[must_use]
+    pub fn with_limits(mut self, lower: f32, upper: f32) -> Self {
+        self.limits = Some((lower, upper));
+        self
+    }
+
+    /// Drives the joint at `speed` units per second along the axis with at most `max_force`.
+    #[must_use]
+    pub fn with_motor(mut self, speed: f32, max_force: f32) -> Self {
+        self.motor = Some(Motor { speed, max_force });
+        self
+    }
+
+    /// Returns the current translation of the joint between two objects.
+    #[must_use]
+    pub fn translation(&self, obj1: &Object2d, obj2: &Object2d) -> f32 {
+        let (_, axis, delta) = self.geometry(obj1, obj2);
+        axis * delta
+    }
+
+    /// Returns the offset of the first anchor, the axis in world coordinates and the vector from
+    /// the first anchor to the second one.
+    fn geometry(&self, obj1: &Object2d, obj2: &Object2d) -> (Vec2d, Vec2d, Vec2d) {
+        let r1 = anchor_offset(obj1, self.anchor1);
+        let r2 = anchor_offset(obj2, self.anchor2);
+        let delta = (obj2.vec + r2) - (obj1.vec + r1);
+        (r1, self.axis.rotated(obj1.angle), delta)
+    }
+
+    /// Returns the torque arms of an impulse along `direction`.
+    fn arms(&self, obj1: &Object2d, obj2: &Object2d, direction: Vec2d) -> (f32, f32) {
+        let (r1, _, delta) = self.geometry(obj1, obj2);
+        let r2 = anchor_offset(obj2, self.anchor2);
+        ((delta + r1).cross(direction), r2.cross(direction))
+    }
+}
+
+impl Constraint for PrismaticJoint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, delta_time: f32) {
+        let (_, axis, delta) = self.geometry(obj1, obj2);
+        self.axis_world = axis;
+        self.axial_arms = self.arms(obj1, obj2, axis);
+        self.perpendicular_arms = self.arms(obj1, obj2, axis.perp());
+        self.axial_mass = axial_mass(obj1, obj2, self.axial_arms);
+        self.perpendicular_mass = axial_mass(obj1, obj2, self.perpendicular_arms);
+        self.angular_mass = angular_mass(obj1, obj2);
+        self.translation = axis * delta;
+        self.inverse_delta_time = if delta_time > 0.0 {
+            1.0 / delta_time
+        } else {
+            0.0
+        };
+        self.max_motor_impulse = self.motor.map_or(0.0, |motor| motor.max_force * delta_time);
+        self.motor_impulse = self
+            .motor_impulse
+            .clamp(-self.max_motor_impulse, self.max_motor_impulse);
+        if self.limits.is_none() {
+            self.lower_impulse = 0.0;
+            self.upper_impulse = 0.0;
+        }
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let axial_impulse = self.motor_impulse + self.lower_impulse - self.upper_impulse;
+        apply_axial_impulse(obj1, obj2, self.axis_world, self.axial_arms, axial_impulse);
+        let perpendicular = self.axis_world.perp();
+        let arms = self.perpendicular_arms;
+        apply_axial_impulse(obj1, obj2, perpendicular, arms, self.perpendicular_impulse);
+        apply_angular_impulse(obj1, obj2, self.angular_impulse);
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let axis = self.axis_world;
+        if let Some(motor) = self.motor {
+            let speed = axial_speed(obj1, obj2, axis, self.axial_arms);
+            let accumulated = (self.motor_impulse - (speed - motor.speed) * self.axial_mass)
+                .clamp(-self.max_motor_impulse, self.max_motor_impulse);
+            let impulse = accumulated - self.motor_impulse;
+            apply_axial_impulse(obj1, obj2, axis, self.axial_arms, impulse);
+            self.motor_impulse = accumulated;
+        }
+        if let Some((lower, upper)) = self.limits {
+            // Speculative limits, like the ones of a revolute joint.
+            let speed = axial_speed(obj1, obj2, axis, self.axial_arms);
+            let bias = (self.translation - lower).max(0.0) * self.inverse_delta_time;
+            let accumulated = (self.lower_impulse - (speed + bias) * self.axial_mass).max(0.0);
+            let impulse = accumulated - self.lower_impulse;
+            apply_axial_impulse(obj1, obj2, axis, self.axial_arms, impulse);
+            self.lower_impulse = accumulated;
+
+            let speed = axial_speed(obj1, obj2, axis, self.axial_arms);
+            let bias = (upper - self.translation).max(0.0) * self.inverse_delta_time;
+            let accumulated = (self.upper_impulse - (bias - speed) * self.axial_mass).max(0.0);
+            let impulse = self.upper_impulse - accumulated;
+            apply_axial_impulse(obj1, obj2, axis, self.axial_arms, impulse);
+            self.upper_impulse = accumulated;
+        }
+        let relative = obj2.angular_velocity - obj1.angular_velocity;
+        let impulse = -relative * self.angular_mass;
+        apply_angular_impulse(obj1, obj2, impulse);
+        self.angular_impulse += impulse;
+
+        let perpendicular = axis.perp();
+        let speed = axial_speed(obj1, obj2, perpendicular, self.perpendicular_arms);
+        let impulse = -speed * self.perpendicular_mass;
+        apply_axial_impulse(obj1, obj2, perpendicular, self.perpendicular_arms, impulse);
+        self.perpendicular_impulse += impulse;
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let error = obj2.angle - obj1.angle - self.reference_angle;
+        apply_angular_correction(obj1, obj2, -error * angular_mass(obj1, obj2));
+
+        let (_, axis, delta) = self.geometry(obj1, obj2);
+        if let Some((lower, upper)) = self.limits {
+            let translation = axis * delta;
+            let error = translation - translation.clamp(lower, upper);
+            let arms = self.arms(obj1, obj2, axis);
+            let correction = -error * axial_mass(obj1, obj2, arms);
+            apply_axial_correction(obj1, obj2, axis, arms, correction);
+        }
+
+        let (_, axis, delta) = self.geometry(obj1, obj2);
+        let perpendicular = axis.perp();
+        let arms = self.arms(obj1, obj2, perpendicular);
+        let correction = -(perpendicular * delta) * axial_mass(obj1, obj2, arms);
+        apply_axial_correction(obj1, obj2, perpendicular, arms, correction);
+    }
+}
+
+/// Returns how fast the second object moves relative to the first one along `direction`.
+fn axial_speed(
+    obj1: &Object2d,
+    obj2: &Object2d,
+    direction: Vec2d,
+    (arm1, arm2): (f32, f32),
+) -> f32 {
+    direction * (obj2.velocity - obj1.velocity) + arm2 * obj2.angular_velocity
+        - arm1 * obj1.angular_velocity
+}
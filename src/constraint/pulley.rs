@@ -0,0 +1,158 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{anchor_offset, apply_correction, Constraint};
+
+/// A rope running over two fixed pulleys, tying how far each object hangs from its pulley.
+///
+/// The rope keeps `length1 + ratio * length2` equal to `length`, where `length1` and `length2`
+/// are the distances from each pulley to the anchor of its object. A `ratio` above one makes the
+/// second side move slower than the first one, like a block and tackle.
+///
+/// # Fields
+///
+/// - `pulley1`: The pulley of the first object, in world coordinates.
+/// - `pulley2`: The pulley of the second object, in world coordinates.
+/// - `anchor1`: The anchor on the first object.
+/// - `anchor2`: The anchor on the second object.
+/// - `ratio`: How much rope the second side takes for each unit of the first one.
+/// - `length`: The total length of the rope, counting the second side `ratio` times.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     constraint::pulley::PulleyJoint,
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2dBuilder,
+/// # };
+/// let mut world = World2dBuilder::new().gravity(vec2(0.0, 10.0)).build();
+/// let heavy = world.add_body(Object2dBuilder::new().mass(3.0).position(vec2(-1.0, 2.0)).build());
+/// let light = world.add_body(Object2dBuilder::new().mass(1.0).position(vec2(1.0, 2.0)).build());
+/// let rope = PulleyJoint::new(vec2(-1.0, 0.0), vec2(1.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0), 1.0, 4.0);
+/// world.add_joint(heavy, Some(light), rope);
+///
+/// world.step(0.5);
+///
+/// // The pair accelerates at (3 - 1) / (3 + 1) * g.
+/// let (heavy, light) = (world.body(heavy).unwrap(), world.body(light).unwrap());
+/// assert!((heavy.velocity.y - 2.5).abs() < 1e-3);
+/// assert!((light.velocity.y + 2.5).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PulleyJoint {
+    pub pulley1: Vec2d,
+    pub pulley2: Vec2d,
+    pub anchor1: Vec2d,
+    pub anchor2: Vec2d,
+    pub ratio: f32,
+    pub length: f32,
+    r1: Vec2d,
+    r2: Vec2d,
+    direction1: Vec2d,
+    direction2: Vec2d,
+    mass: f32,
+    impulse: f32,
+}
+
+impl PulleyJoint {
+    /// Creates a new `PulleyJoint`.
+    #[must_use]
+    pub fn new(
+        pulley1: Vec2d,
+        pulley2: Vec2d,
+        anchor1: Vec2d,
+        anchor2: Vec2d,
+        ratio: f32,
+        length: f32,
+    ) -> Self {
+        Self {
+            pulley1,
+            pulley2,
+            anchor1,
+            anchor2,
+            ratio,
+            length,
+            r1: Vec2d::ZERO,
+            r2: Vec2d::ZERO,
+            direction1: Vec2d::ZERO,
+            direction2: Vec2d::ZERO,
+            mass: 0.0,
+            impulse: 0.0,
+        }
+    }
+
+    /// Returns the current lengths of both sides of the rope.
+    #[must_use]
+    pub fn lengths(&self, obj1: &Object2d, obj2: &Object2d) -> (f32, f32) {
+        let (.., length1, length2) = self.geometry(obj1, obj2);
+        (length1, length2)
+    }
+
+    /// Pulls both objects towards their pulleys with the tension `impulse`.
+    fn apply(&self, obj1: &mut Object2d, obj2: &mut Object2d, impulse: f32) {
+        obj1.apply_impulse_at(self.direction1 * impulse, self.r1);
+        obj2.apply_impulse_at(self.direction2 * (self.ratio * impulse), self.r2);
+    }
+
+    /// Returns the anchor offsets, the unit vectors from each pulley to its anchor, the inverse
+    /// mass of the rope and the lengths of both sides.
+    fn geometry(
+        &self,
+        obj1: &Object2d,
+        obj2: &Object2d,
+    ) -> (Vec2d, Vec2d, Vec2d, Vec2d, f32, f32, f32) {
+        let r1 = anchor_offset(obj1, self.anchor1);
+        let r2 = anchor_offset(obj2, self.anchor2);
+        let (side1, side2) = (obj1.vec + r1 - self.pulley1, obj2.vec + r2 - self.pulley2);
+        let (length1, length2) = (side1.magnitude(), side2.magnitude());
+        let direction1 = if length1 > 0.0 {
+            side1 / length1
+        } else {
+            Vec2d::ZERO
+        };
+        let direction2 = if length2 > 0.0 {
+            side2 / length2
+        } else {
+            Vec2d::ZERO
+        };
+        let inverse_mass = obj1.inverse_mass_at(r1, direction1)
+            + self.ratio * self.ratio * obj2.inverse_mass_at(r2, direction2);
+        let mass = if inverse_mass > 0.0 {
+            1.0 / inverse_mass
+        } else {
+            0.0
+        };
+        (r1, r2, direction1, direction2, mass, length1, length2)
+    }
+}
+
+impl Constraint for PulleyJoint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, _delta_time: f32) {
+        let (r1, r2, direction1, direction2, mass, ..) = self.geometry(obj1, obj2);
+        self.r1 = r1;
+        self.r2 = r2;
+        self.direction1 = direction1;
+        self.direction2 = direction2;
+        self.mass = mass;
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        self.apply(obj1, obj2, self.impulse);
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let speed = obj1.point_velocity(self.r1) * self.direction1
+            + self.ratio * (obj2.point_velocity(self.r2) * self.direction2);
+        let impulse = -speed * self.mass;
+        self.apply(obj1, obj2, impulse);
+        self.impulse += impulse;
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let (r1, r2, direction1, direction2, mass, length1, length2) = self.geometry(obj1, obj2);
+        let correction = -(length1 + self.ratio * length2 - self.length) * mass;
+        apply_correction(obj1, direction1 * correction, r1);
+        apply_correction(obj2, direction2 * (self.ratio * correction), r2);
+    }
+}
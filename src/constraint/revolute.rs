@@ -0,0 +1,185 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{
+    anchor_offset, angular_mass, apply_angular_correction, apply_angular_impulse,
+    solve_point_position, solve_point_velocity, Constraint, Motor, PointMass,
+};
+
+/// A hinge letting two objects rotate around a shared point, for doors, wheels and arms.
+///
+/// The anchors are given in the local frame of each object. The angle of the joint is the angle
+/// of the second object relative to the first one minus `reference_angle`, it can be kept
+/// within limits and driven by a [`Motor`].
+///
+/// # Fields
+///
+/// - `anchor1`: The anchor on the first object.
+/// - `anchor2`: The anchor on the second object.
+/// - `reference_angle`: The relative angle of the objects when the joint angle is zero.
+/// - `limits`: The optional lower and upper joint angle.
+/// - `motor`: The optional motor driving the joint.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::shape::Shape,
+/// #     constraint::revolute::RevoluteJoint,
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2d,
+/// # };
+/// let mut world = World2d::new();
+/// let wheel = world.add_body(
+///     Object2dBuilder::new().mass(1.0).radius(0.5).shape(Shape::Circle).position(vec2(2.0, 1.0)).build(),
+/// );
+/// // An axle fixed to the world. The motor turns the world relative to the wheel, so a negative
+/// // speed turns the wheel forwards at two radians per second.
+/// world.add_joint(wheel, None, RevoluteJoint::new(vec2(0.0, 0.0), vec2(2.0, 1.0)).with_motor(-2.0, 10.0));
+///
+/// for _ in 0..60 {
+///     world.step(1.0 / 60.0);
+/// }
+///
+/// let wheel = world.body(wheel).unwrap();
+/// assert!((wheel.angular_velocity - 2.0).abs() < 1e-3);
+/// assert!(wheel.vec.distance(vec2(2.0, 1.0)) < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RevoluteJoint {
+    pub anchor1: Vec2d,
+    pub anchor2: Vec2d,
+    pub reference_angle: f32,
+    pub limits: Option<(f32, f32)>,
+    pub motor: Option<Motor>,
+    r1: Vec2d,
+    r2: Vec2d,
+    point_mass: PointMass,
+    point_impulse: Vec2d,
+    angular_mass: f32,
+    angle: f32,
+    inverse_delta_time: f32,
+    max_motor_impulse: f32,
+    motor_impulse: f32,
+    lower_impulse: f32,
+    upper_impulse: f32,
+}
+
+impl RevoluteJoint {
+    /// Creates a new free `RevoluteJoint` between two local anchors.
+    #[must_use]
+    pub fn new(anchor1: Vec2d, anchor2: Vec2d) -> Self {
+        Self {
+            anchor1,
+            anchor2,
+            reference_angle: 0.0,
+            limits: None,
+            motor: None,
+            r1: Vec2d::ZERO,
+            r2: Vec2d::ZERO,
+            point_mass: PointMass::default(),
+            point_impulse: Vec2d::ZERO,
+            angular_mass: 0.0,
+            angle: 0.0,
+            inverse_delta_time: 0.0,
+            max_motor_impulse: 0.0,
+            motor_impulse: 0.0,
+            lower_impulse: 0.0,
+            upper_impulse: 0.0,
+        }
+    }
+
+    /// Sets the relative angle of the objects when the joint angle is zero.
+    #[must_use]
+    pub fn with_reference_angle(mut self, reference_angle: f32) -> Self {
+        self.reference_angle = reference_angle;
+        self
+    }
+
+    /// Keeps the joint angle between `lower` and `upper` radians.
+    #[must_use]
+    pub fn with_limits(mut self, lower: f32, upper: f32) -> Self {
+        self.limits = Some((lower, upper));
+        self
+    }
+
+    /// Drives the joint at `speed` radians per second with at most `max_torque`.
+    #[must_use]
+    pub fn with_motor(mut self, speed: f32, max_torque: f32) -> Self {
+        self.motor = Some(Motor {
+            speed,
+            max_force: max_torque,
+        });
+        self
+    }
+
+    /// Returns the current joint angle between two objects.
+    #[must_use]
+    pub fn angle(&self, obj1: &Object2d, obj2: &Object2d) -> f32 {
+        obj2.angle - obj1.angle - self.reference_angle
+    }
+}
+
+impl Constraint for RevoluteJoint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, delta_time: f32) {
+        self.r1 = anchor_offset(obj1, self.anchor1);
+        self.r2 = anchor_offset(obj2, self.anchor2);
+        self.point_mass = PointMass::new(obj1, obj2, self.r1, self.r2);
+        self.angular_mass = angular_mass(obj1, obj2);
+        self.angle = self.angle(obj1, obj2);
+        self.inverse_delta_time = if delta_time > 0.0 {
+            1.0 / delta_time
+        } else {
+            0.0
+        };
+        self.max_motor_impulse = self.motor.map_or(0.0, |motor| motor.max_force * delta_time);
+        self.motor_impulse = self
+            .motor_impulse
+            .clamp(-self.max_motor_impulse, self.max_motor_impulse);
+        if self.limits.is_none() {
+            self.lower_impulse = 0.0;
+            self.upper_impulse = 0.0;
+        }
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let angular_impulse = self.motor_impulse + self.lower_impulse - self.upper_impulse;
+        apply_angular_impulse(obj1, obj2, angular_impulse);
+        obj1.apply_impulse_at(-self.point_impulse, self.r1);
+        obj2.apply_impulse_at(self.point_impulse, self.r2);
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        if let Some(motor) = self.motor {
+            let relative = obj2.angular_velocity - obj1.angular_velocity;
+            let accumulated = (self.motor_impulse - (relative - motor.speed) * self.angular_mass)
+                .clamp(-self.max_motor_impulse, self.max_motor_impulse);
+            apply_angular_impulse(obj1, obj2, accumulated - self.motor_impulse);
+            self.motor_impulse = accumulated;
+        }
+        if let Some((lower, upper)) = self.limits {
+            // Speculative limits: the joint may still close the gap left to each limit this step.
+            let relative = obj2.angular_velocity - obj1.angular_velocity;
+            let bias = (self.angle - lower).max(0.0) * self.inverse_delta_time;
+            let accumulated = (self.lower_impulse - (relative + bias) * self.angular_mass).max(0.0);
+            apply_angular_impulse(obj1, obj2, accumulated - self.lower_impulse);
+            self.lower_impulse = accumulated;
+
+            let relative = obj2.angular_velocity - obj1.angular_velocity;
+            let bias = (upper - self.angle).max(0.0) * self.inverse_delta_time;
+            let accumulated = (self.upper_impulse - (bias - relative) * self.angular_mass).max(0.0);
+            apply_angular_impulse(obj1, obj2, self.upper_impulse - accumulated);
+            self.upper_impulse = accumulated;
+        }
+        self.point_impulse += solve_point_velocity(obj1, obj2, self.r1, self.r2, &self.point_mass);
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        if let Some((lower, upper)) = self.limits {
+            let angle = self.angle(obj1, obj2);
+            let error = angle - angle.clamp(lower, upper);
+            apply_angular_correction(obj1, obj2, -error * angular_mass(obj1, obj2));
+        }
+        solve_point_position(obj1, obj2, self.anchor1, self.anchor2);
+    }
+}
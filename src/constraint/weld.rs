@@ -0,0 +1,140 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{
+    anchor_offset, angular_mass, apply_angular_correction, apply_angular_impulse,
+    solve_point_position, solve_point_velocity, Constraint, PointMass,
+};
+
+/// Glues two objects together, keeping their anchors at the same point and their relative angle
+/// fixed.
+///
+/// A weld joint may break: once the impulse needed to hold the objects together during a step
+/// exceeds `breaking_impulse` the joint stops acting, which is how compound objects shatter.
+///
+/// # Fields
+///
+/// - `anchor1`: The anchor on the first object.
+/// - `anchor2`: The anchor on the second object.
+/// - `reference_angle`: The relative angle kept between the objects.
+/// - `breaking_impulse`: The optional largest linear impulse the joint withstands in a step.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     constraint::{weld::WeldJoint, Joint},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2dBuilder,
+/// # };
+/// let mut world = World2dBuilder::new().gravity(vec2(0.0, 10.0)).build();
+/// let shelf = world.add_body(Object2dBuilder::new().mass(1.0).build());
+/// let vase = world.add_body(Object2dBuilder::new().mass(1.0).position(vec2(0.0, -1.0)).build());
+/// world.add_joint(shelf, None, WeldJoint::new(vec2(0.0, 0.0), vec2(0.0, 0.0)));
+/// let glue = world.add_joint(vase, Some(shelf), WeldJoint::new(vec2(0.0, 1.0), vec2(0.0, 0.0)).with_breaking_impulse(1.0));
+///
+/// world.step(0.05);
+/// assert!(world.body(vase).unwrap().vec.distance(vec2(0.0, -1.0)) < 1e-3);
+///
+/// world.body_mut(vase).unwrap().add_impulse(vec2(5.0, 0.0));
+/// world.step(0.05);
+/// assert!(matches!(world.joint(glue), Some(Joint::Weld(weld)) if weld.is_broken()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeldJoint {
+    pub anchor1: Vec2d,
+    pub anchor2: Vec2d,
+    pub reference_angle: f32,
+    pub breaking_impulse: Option<f32>,
+    broken: bool,
+    r1: Vec2d,
+    r2: Vec2d,
+    point_mass: PointMass,
+    angular_mass: f32,
+    impulse: Vec2d,
+    angular_impulse: f32,
+}
+
+impl WeldJoint {
+    /// Creates a new unbreakable `WeldJoint` between two local anchors.
+    #[must_use]
+    pub fn new(anchor1: Vec2d, anchor2: Vec2d) -> Self {
+        Self {
+            anchor1,
+            anchor2,
+            reference_angle: 0.0,
+            breaking_impulse: None,
+            broken: false,
+            r1: Vec2d::ZERO,
+            r2: Vec2d::ZERO,
+            point_mass: PointMass::default(),
+            angular_mass: 0.0,
+            impulse: Vec2d::ZERO,
+            angular_impulse: 0.0,
+        }
+    }
+
+    /// Sets the relative angle kept between the objects.
+    #[must_use]
+    pub fn with_reference_angle(mut self, reference_angle: f32) -> Self {
+        self.reference_angle = reference_angle;
+        self
+    }
+
+    /// Breaks the joint once it needs more than `breaking_impulse` in a step.
+    #[must_use]
+    pub fn with_breaking_impulse(mut self, breaking_impulse: f32) -> Self {
+        self.breaking_impulse = Some(breaking_impulse);
+        self
+    }
+
+    /// Returns whether the joint broke, a broken joint no longer acts on its objects.
+    #[must_use]
+    pub fn is_broken(&self) -> bool {
+        self.broken
+    }
+}
+
+impl Constraint for WeldJoint {
+    fn prepare(&mut self, obj1: &Object2d, obj2: &Object2d, _delta_time: f32) {
+        self.r1 = anchor_offset(obj1, self.anchor1);
+        self.r2 = anchor_offset(obj2, self.anchor2);
+        self.point_mass = PointMass::new(obj1, obj2, self.r1, self.r2);
+        self.angular_mass = angular_mass(obj1, obj2);
+    }
+
+    fn warm_start(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        if self.broken {
+            return;
+        }
+        apply_angular_impulse(obj1, obj2, self.angular_impulse);
+        obj1.apply_impulse_at(-self.impulse, self.r1);
+        obj2.apply_impulse_at(self.impulse, self.r2);
+    }
+
+    fn solve_velocity(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        if self.broken {
+            return;
+        }
+        let relative = obj2.angular_velocity - obj1.angular_velocity;
+        let angular_impulse = -relative * self.angular_mass;
+        apply_angular_impulse(obj1, obj2, angular_impulse);
+        self.angular_impulse += angular_impulse;
+        self.impulse += solve_point_velocity(obj1, obj2, self.r1, self.r2, &self.point_mass);
+        if self
+            .breaking_impulse
+            .is_some_and(|breaking_impulse| self.impulse.magnitude() > breaking_impulse)
+        {
+            self.broken = true;
+        }
+    }
+
+    fn solve_position(&mut self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        if self.broken {
+            return;
+        }
+        let error = obj2.angle - obj1.angle - self.reference_angle;
+        apply_angular_correction(obj1, obj2, -error * angular_mass(obj1, obj2));
+        solve_point_position(obj1, obj2, self.anchor1, self.anchor2);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::pair::{apply_pairwise, inverse_square, PairForce};
+
+/// The Newtonian gravitational constant in `m³·kg⁻¹·s⁻²`.
+pub const GRAVITATIONAL_CONSTANT: f32 = 6.674e-11;
+
+/// Represents the mutual gravitational attraction between objects, `G·m1·m2/r²`.
+///
+/// A softening length `ε` replaces `r²` with `r² + ε²`, which keeps the force finite when two
+/// objects get very close or overlap.
+///
+/// # Fields
+///
+/// - `constant`: The gravitational constant `G`, simulations usually pick their own scale.
+/// - `softening`: The softening length `ε`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Attraction {
+    pub constant: f32,
+    pub softening: f32,
+}
+
+impl Attraction {
+    /// Computes the acceleration that a mass located at `source` causes at `position`.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: The point where the acceleration is measured.
+    /// - `source`: The position of the attracting mass.
+    /// - `mass`: The attracting mass.
+    #[must_use]
+    pub fn acceleration_towards(&self, position: Vec2d, source: Vec2d, mass: f32) -> Vec2d {
+        inverse_square(position, source, self.softening) * (self.constant * mass)
+    }
+
+    /// Adds the attraction between every pair of objects to their accumulated forces.
+    ///
+    /// This is the direct `O(N²)` sum of [`apply_pairwise`], every pair is visited once and receives
+    /// equal and opposite forces. Only dynamic objects are pushed, but every object attracts the
+    /// others. No memory is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{forces::attraction::attraction, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut bodies = [
+    ///     Object2dBuilder::new().mass(4.0).build(),
+    ///     Object2dBuilder::new().mass(1.0).position(vec2(2.0, 0.0)).build(),
+    ///     Object2dBuilder::new().mass(1.0).position(vec2(-2.0, 0.0)).build(),
+    /// ];
+    ///
+    /// attraction(1.0, 0.0).apply_direct(&mut bodies);
+    ///
+    /// assert_eq!(bodies[0].force, vec2(0.0, 0.0));
+    /// assert_eq!(bodies[1].force, vec2(-1.0 - 0.0625, 0.0));
+    /// assert_eq!(bodies[2].force, vec2(1.0 + 0.0625, 0.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `objects`: The objects that attract each other.
+    pub fn apply_direct(&self, objects: &mut [Object2d]) {
+        apply_pairwise(self, objects);
+    }
+}
+
+impl PairForce for Attraction {
+    /// Computes the force that `other` exerts on `obj`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::{attraction::attraction, pair::PairForce},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let sun = Object2dBuilder::new().mass(100.0).build();
+    /// let planet = Object2dBuilder::new().mass(1.0).position(vec2(10.0, 0.0)).build();
+    ///
+    /// let force = attraction(1.0, 0.0).force_between(&planet, &sun);
+    /// assert_eq!(force, vec2(-1.0, 0.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The object the force acts on.
+    /// - `other`: The object that attracts `obj`.
+    ///
+    /// # Returns
+    ///
+    /// The force acting on `obj`, pointing towards `other`.
+    fn force_between(&self, obj: &Object2d, other: &Object2d) -> Vec2d {
+        self.acceleration_towards(obj.vec, other.vec, other.mass) * obj.mass
+    }
+}
+
+/// Creates a new `Attraction` instance.
+///
+/// # Parameters
+///
+/// - `constant`: The gravitational constant `G`.
+/// - `softening`: The softening length `ε`.
+///
+/// # Returns
+///
+/// A new `Attraction` instance.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::attraction::{attraction, GRAVITATIONAL_CONSTANT};
+/// let newton = attraction(GRAVITATIONAL_CONSTANT, 0.01);
+/// ```
+#[inline]
+#[must_use]
+pub fn attraction(constant: f32, softening: f32) -> Attraction {
+    Attraction {
+        constant,
+        softening,
+    }
+}
@@ -0,0 +1,278 @@
+use alloc::vec::Vec;
+
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::attraction::Attraction;
+
+/// Maximum depth of the quadtree, objects sharing a cell this deep are grouped in a single leaf.
+const MAX_DEPTH: usize = 32;
+
+/// A cell of the quadtree.
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    /// The center of the square covered by the cell.
+    center: Vec2d,
+    /// Half of the side length of the square.
+    half_size: f32,
+    /// The total mass of the objects inside the cell.
+    mass: f32,
+    /// The sum of the positions of the objects inside the cell weighted by their mass.
+    weighted_position: Vec2d,
+    /// The index of the first of the four children, they are stored next to each other.
+    children: Option<usize>,
+    /// The index of the object stored in a leaf.
+    body: Option<usize>,
+}
+
+impl Node {
+    fn new(center: Vec2d, half_size: f32) -> Self {
+        Node {
+            center,
+            half_size,
+            mass: 0.0,
+            weighted_position: Vec2d::ZERO,
+            children: None,
+            body: None,
+        }
+    }
+
+    /// The quadrant of the cell that contains `position`.
+    fn quadrant(&self, position: Vec2d) -> usize {
+        usize::from(position.x >= self.center.x) | (usize::from(position.y >= self.center.y) << 1)
+    }
+}
+
+/// Approximates the mutual attraction of many objects with the Barnes–Hut algorithm.
+///
+/// The objects are sorted into a quadtree, and groups of objects that are far enough away are
+/// replaced by a single mass at their center of mass. A group is far enough when the size of its
+/// cell divided by its distance is less than the opening angle `θ`, with `θ = 0.0` every object
+/// is visited and the result matches [`Attraction::apply_direct`]. Building the tree and
+/// evaluating every object take `O(N log N)`.
+///
+/// # Fields
+///
+/// - `attraction`: The attraction law between two masses.
+/// - `theta`: The opening angle `θ`, `0.5` is a common trade-off between speed and accuracy.
+///
+/// # Examples
+///
+/// Comparing the approximation with the direct sum:
+///
+/// ```
+/// # use motion::{
+/// #     forces::{attraction::attraction, barnes_hut::BarnesHut},
+/// #     obj::obj_2d::{Object2d, Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut seed = 12345_u32;
+/// let mut random = move || {
+///     seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+///     (seed >> 8) as f32 / (1 << 24) as f32
+/// };
+/// let mut bodies: Vec<Object2d> = (0..300)
+///     .map(|_| {
+///         Object2dBuilder::new()
+///             .position(vec2(random() * 100.0, random() * 100.0))
+///             .mass(1.0 + random())
+///             .build()
+///     })
+///     .collect();
+///
+/// let newton = attraction(1.0, 0.1);
+/// let approximated = BarnesHut { attraction: newton, theta: 0.5 }.accelerations(&bodies);
+/// let exhaustive = BarnesHut { attraction: newton, theta: 0.0 }.accelerations(&bodies);
+/// newton.apply_direct(&mut bodies);
+///
+/// let (mut error, mut total) = (0.0, 0.0);
+/// for (i, obj) in bodies.iter().enumerate() {
+///     let exact = obj.force / obj.mass;
+///     let difference = exact - approximated[i];
+///     error += difference * difference;
+///     total += exact * exact;
+///     assert!((exact - exhaustive[i]).magnitude() <= 1e-3 * exact.magnitude());
+/// }
+/// // The root mean square error stays below 1% of the exact accelerations.
+/// assert!(error < 1e-4 * total);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BarnesHut {
+    pub attraction: Attraction,
+    pub theta: f32,
+}
+
+impl BarnesHut {
+    /// Computes the gravitational acceleration of every object caused by all the others.
+    ///
+    /// Objects without mass feel the attraction of the others but do not attract anything.
+    ///
+    /// # Parameters
+    ///
+    /// - `objects`: The objects that attract each other.
+    ///
+    /// # Returns
+    ///
+    /// The acceleration of each object, in the same order as `objects`.
+    #[must_use]
+    pub fn accelerations(&self, objects: &[Object2d]) -> Vec<Vec2d> {
+        let nodes = build_tree(objects);
+        objects
+            .iter()
+            .map(|obj| {
+                if nodes.is_empty() {
+                    Vec2d::ZERO
+                } else {
+                    self.acceleration_from(&nodes, 0, obj, true)
+                }
+            })
+            .collect()
+    }
+
+    /// Adds the approximated attraction to the accumulated forces of every dynamic object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::{attraction::attraction, barnes_hut::BarnesHut},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut bodies = [
+    ///     Object2dBuilder::new().mass(1.0).build(),
+    ///     Object2dBuilder::new().mass(1.0).position(vec2(1.0, 0.0)).build(),
+    /// ];
+    /// BarnesHut { attraction: attraction(1.0, 0.0), theta: 0.5 }.apply(&mut bodies);
+    ///
+    /// assert_eq!(bodies[0].force, vec2(1.0, 0.0));
+    /// assert_eq!(bodies[1].force, vec2(-1.0, 0.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `objects`: The objects that attract each other.
+    pub fn apply(&self, objects: &mut [Object2d]) {
+        let accelerations = self.accelerations(objects);
+        for (obj, acceleration) in objects.iter_mut().zip(accelerations) {
+            if obj.body_type.is_dynamic() {
+                obj.add_force(acceleration * obj.mass);
+            }
+        }
+    }
+
+    /// Acceleration of `obj` caused by the cell `node`.
+    ///
+    /// `on_path` tells whether the cell contains the object itself, such cells are always opened
+    /// and the object is removed from the leaf it was stored in.
+    fn acceleration_from(
+        &self,
+        nodes: &[Node],
+        node: usize,
+        obj: &Object2d,
+        on_path: bool,
+    ) -> Vec2d {
+        let cell = &nodes[node];
+        if cell.mass <= 0.0 {
+            return Vec2d::ZERO;
+        }
+        match cell.children {
+            None => {
+                let (mut mass, mut weighted_position) = (cell.mass, cell.weighted_position);
+                if on_path && obj.mass > 0.0 {
+                    mass -= obj.mass;
+                    weighted_position -= obj.vec * obj.mass;
+                }
+                if mass <= f32::EPSILON * cell.mass {
+                    return Vec2d::ZERO;
+                }
+                self.attraction
+                    .acceleration_towards(obj.vec, weighted_position / mass, mass)
+            }
+            Some(first) => {
+                let center_of_mass = cell.weighted_position / cell.mass;
+                let distance = obj.vec.distance(center_of_mass);
+                if !on_path && 2.0 * cell.half_size < self.theta * distance {
+                    return self.attraction.acceleration_towards(
+                        obj.vec,
+                        center_of_mass,
+                        cell.mass,
+                    );
+                }
+                let quadrant = cell.quadrant(obj.vec);
+                let mut acceleration = Vec2d::ZERO;
+                for child in 0..4 {
+                    acceleration += self.acceleration_from(
+                        nodes,
+                        first + child,
+                        obj,
+                        on_path && child == quadrant,
+                    );
+                }
+                acceleration
+            }
+        }
+    }
+}
+
+/// Builds the quadtree of the objects with mass, the root is the first node.
+fn build_tree(objects: &[Object2d]) -> Vec<Node> {
+    let mut bodies = objects.iter().filter(|obj| obj.mass > 0.0);
+    let Some(first) = bodies.next() else {
+        return Vec::new();
+    };
+    let (mut min, mut max) = (first.vec, first.vec);
+    for obj in bodies {
+        min = Vec2d::new(min.x.min(obj.vec.x), min.y.min(obj.vec.y));
+        max = Vec2d::new(max.x.max(obj.vec.x), max.y.max(obj.vec.y));
+    }
+    let size = min.component(max);
+    let half_size = 0.5 * size.x.max(size.y) + 1.0;
+
+    let mut nodes = Vec::with_capacity(2 * objects.len());
+    nodes.push(Node::new((min + max) / 2.0, half_size));
+    for (index, obj) in objects.iter().enumerate() {
+        if obj.mass > 0.0 {
+            insert(&mut nodes, 0, objects, index, 0);
+        }
+    }
+    nodes
+}
+
+/// Inserts the object `index` into the cell `node`, subdividing leaves that are already taken.
+fn insert(nodes: &mut Vec<Node>, node: usize, objects: &[Object2d], index: usize, depth: usize) {
+    let obj = &objects[index];
+    let was_empty = nodes[node].mass <= 0.0;
+    nodes[node].mass += obj.mass;
+    nodes[node].weighted_position += obj.vec * obj.mass;
+
+    if let Some(first) = nodes[node].children {
+        let quadrant = nodes[node].quadrant(obj.vec);
+        insert(nodes, first + quadrant, objects, index, depth + 1);
+        return;
+    }
+    if was_empty {
+        nodes[node].body = Some(index);
+        return;
+    }
+    if depth >= MAX_DEPTH {
+        return;
+    }
+
+    let first = nodes.len();
+    let Node {
+        center, half_size, ..
+    } = nodes[node];
+    let quarter = 0.5 * half_size;
+    for quadrant in 0..4 {
+        let x = if quadrant & 1 == 0 { -quarter } else { quarter };
+        let y = if quadrant & 2 == 0 { -quarter } else { quarter };
+        nodes.push(Node::new(center + Vec2d::new(x, y), quarter));
+    }
+    nodes[node].children = Some(first);
+    if let Some(previous) = nodes[node].body.take() {
+        let quadrant = nodes[node].quadrant(objects[previous].vec);
+        insert(nodes, first + quadrant, objects, previous, depth + 1);
+    }
+    let quadrant = nodes[node].quadrant(obj.vec);
+    insert(nodes, first + quadrant, objects, index, depth + 1);
+}
@@ -0,0 +1,173 @@
+use crate::{
+    collision::{polygon::Convex, shape::Shape},
+    formulas::{acos::acos, sqrt::sqrt},
+    obj::obj_2d::Object2d,
+    vec::vec_2d::Vec2d,
+};
+
+use super::{combinator::Scalable, drag::Drag, force::Force};
+
+/// Represents the buoyancy of objects partially or totally submerged in a fluid.
+///
+/// The fluid fills everything below a horizontal surface line, that is every point with `y`
+/// greater than `surface`, matching the screen coordinates of [`EARTH_GRAVITY`](super::gravity::EARTH_GRAVITY).
+/// Submerged objects are pushed against gravity with the weight of the fluid they displace,
+/// `ρ·A·g`, where the submerged area `A` plays the role of the displaced volume in 2D.
+///
+/// # Fields
+///
+/// - `surface`: The `y` coordinate of the surface of the fluid.
+/// - `fluid_density`: The density `ρ` of the fluid.
+/// - `gravity`: The gravitational acceleration `g` acting on the fluid.
+/// - `drag`: An optional drag suffered inside the fluid, scaled by the submerged fraction of the object.
+///
+/// # Examples
+///
+/// A crate half as dense as water floats half submerged:
+///
+/// ```
+/// # use motion::{
+/// #     collision::shape::Shape,
+/// #     forces::{buoyancy::buoyancy, drag::WATER_DENSITY, gravity::{gravity, EARTH_GRAVITY}},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut crate_ = Object2dBuilder::new()
+///     .position(vec2(0.0, 10.0))
+///     .shape(Shape::AABB(vec2(-1.0, -1.0), vec2(1.0, 1.0)))
+///     .mass(0.5 * WATER_DENSITY * 4.0)
+///     .build();
+///
+/// crate_.apply(&gravity(EARTH_GRAVITY));
+/// crate_.apply(&buoyancy(10.0, WATER_DENSITY, EARTH_GRAVITY));
+/// crate_.integrate(1.0);
+///
+/// assert!(crate_.velocity.magnitude() < 1e-4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Buoyancy {
+    pub surface: f32,
+    pub fluid_density: f32,
+    pub gravity: Vec2d,
+    pub drag: Option<Drag>,
+}
+
+impl Buoyancy {
+    /// Adds a drag suffered by the objects while they are inside the fluid.
+    #[must_use]
+    pub fn with_drag(mut self, drag: Drag) -> Self {
+        self.drag = Some(drag);
+        self
+    }
+
+    /// Computes the area of an object that lies below the surface of the fluid.
+    ///
+    /// Circles use the area of the submerged circular segment, AABBs the area of the submerged
+    /// rectangle and polygons the area of the polygon clipped by the surface, compounds add up
+    /// the submerged area of their children, and objects without a shape, half-planes,
+    /// heightfields and custom shapes are never buoyed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::shape::Shape,
+    /// #     forces::{buoyancy::buoyancy, gravity::EARTH_GRAVITY},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let water = buoyancy(0.0, 1000.0, EARTH_GRAVITY);
+    /// let ball = Object2dBuilder::new().radius(1.0).shape(Shape::Circle).build();
+    ///
+    /// let area = water.submerged_area(&ball);
+    /// assert!((area - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+    /// ```
+    #[must_use]
+    pub fn submerged_area(&self, obj: &Object2d) -> f32 {
+        match obj.shape {
+            Shape::None | Shape::HalfPlane(_) | Shape::Heightfield(..) | Shape::Custom(_) => 0.0,
+            Shape::Circle => {
+                let radius = obj.radius;
+                let depth = (obj.vec.y + radius - self.surface).clamp(0.0, 2.0 * radius);
+                if depth == 0.0 {
+                    return 0.0;
+                }
+                let height = radius - depth;
+                radius * radius * acos(height / radius)
+                    - height * sqrt(2.0 * radius * depth - depth * depth)
+            }
+            Shape::AABB(min, max) => {
+                let (top, bottom) = (obj.vec.y + min.y, obj.vec.y + max.y);
+                let depth = (bottom - self.surface.max(top)).max(0.0);
+                depth * (max.x - min.x).abs()
+            }
+            Shape::Rect(..) | Shape::Polygon(_) => {
+                Convex::of(obj).map_or(0.0, |convex| convex.area_below(self.surface))
+            }
+            Shape::Compound(_) => obj
+                .children()
+                .map(|child| self.submerged_area(&child))
+                .sum(),
+        }
+    }
+}
+
+impl Force for Buoyancy {
+    /// Adds the buoyant force, and the drag inside the fluid if any, to a 2D object.
+    ///
+    /// Only dynamic objects are affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The 2D object to which the force is applied.
+    fn apply_2d(&self, obj: &mut Object2d) {
+        if !obj.body_type.is_dynamic() {
+            return;
+        }
+        let submerged = self.submerged_area(obj);
+        if submerged <= 0.0 {
+            return;
+        }
+        obj.add_force(-self.gravity * (self.fluid_density * submerged));
+        if let Some(drag) = self.drag {
+            let fraction = (submerged / obj.area()).min(1.0);
+            let area = obj.cross_section(obj.velocity) * fraction;
+            obj.add_force(drag.force(obj.velocity, area));
+        }
+    }
+}
+
+impl Scalable for Buoyancy {}
+
+/// Creates a new `Buoyancy` instance without drag.
+///
+/// # Parameters
+///
+/// - `surface`: The `y` coordinate of the surface of the fluid.
+/// - `fluid_density`: The density of the fluid.
+/// - `gravity`: The gravitational acceleration acting on the fluid.
+///
+/// # Returns
+///
+/// A new `Buoyancy` instance.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::{
+/// #     buoyancy::buoyancy,
+/// #     drag::{quadratic_drag, WATER_DENSITY},
+/// #     gravity::EARTH_GRAVITY,
+/// # };
+/// let water = buoyancy(300.0, WATER_DENSITY, EARTH_GRAVITY).with_drag(quadratic_drag(1.0, WATER_DENSITY));
+/// ```
+#[inline]
+#[must_use]
+pub fn buoyancy(surface: f32, fluid_density: f32, gravity: Vec2d) -> Buoyancy {
+    Buoyancy {
+        surface,
+        fluid_density,
+        gravity,
+        drag: None,
+    }
+}
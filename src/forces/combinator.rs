@@ -0,0 +1,248 @@
+use crate::obj::obj_2d::Object2d;
+
+use super::{
+    force::TimedForce,
+    timed::{Modulated, Pulse, Ramp, Sinusoidal},
+};
+
+/// Combinators to sum, scale, gate and drive any [`Force`](super::force::Force) over time.
+///
+/// Every force implements `ForceExt`, the combinators take the force by value and can be
+/// chained.
+///
+/// # Time
+///
+/// The combined forces read the time given to [`TimedForce::apply_2d_at`], so they only
+/// implement [`TimedForce`]. Apply them with [`Object2d::apply_at`], or `World2d::apply` which
+/// passes the time of the world:
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let push = uniform(vec2(2.0, 0.0)).active_between(1.0, 2.0);
+/// let mut obj = Object2dBuilder::new().build();
+///
+/// obj.apply_at(&push, 1.5);
+/// assert_eq!(obj.force, vec2(2.0, 0.0));
+/// ```
+///
+/// Applying them without a time does not compile:
+///
+/// ```compile_fail
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let push = uniform(vec2(2.0, 0.0)).active_between(1.0, 2.0);
+/// let mut obj = Object2dBuilder::new().build();
+///
+/// obj.apply(&push);
+/// ```
+///
+/// # Scaling
+///
+/// [`ForceExt::scaled`], [`ForceExt::ramped`], [`ForceExt::oscillating`] and
+/// [`ForceExt::modulated`] scale what the force adds to the accumulated forces, torques,
+/// impulses and accelerations of the object, so they are only available for [`Scalable`]
+/// forces. Forces that change its position or velocity directly, like
+/// [`Move2d`](super::move::Move2d), cannot be scaled:
+///
+/// ```compile_fail
+/// # use motion::forces::{combinator::ForceExt, r#move::move2d};
+/// let half_move = move2d(1.0 / 60.0).scaled(0.5);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform, gravity::gravity},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let wind = uniform(vec2(2.0, 0.0)).scaled(0.5).active_between(1.0, 3.0);
+/// let forces = gravity(vec2(0.0, 10.0)).and(wind);
+///
+/// let mut obj = Object2dBuilder::new().mass(2.0).build();
+/// obj.apply_at(&forces, 2.0);
+/// assert_eq!(obj.force, vec2(1.0, 0.0));
+/// assert_eq!(obj.added_acceleration, vec2(0.0, 10.0));
+/// ```
+pub trait ForceExt: TimedForce + Sized {
+    /// Applies both forces, one after the other.
+    fn and<B: TimedForce>(self, other: B) -> And<Self, B> {
+        And {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Multiplies the force by a constant `factor`, see [scaling](ForceExt#scaling).
+    fn scaled(self, factor: f32) -> Scaled<Self>
+    where
+        Self: Scalable,
+    {
+        Scaled {
+            force: self,
+            factor,
+        }
+    }
+
+    /// Applies the force only from `start` until `end` seconds of simulation, end excluded.
+    fn active_between(self, start: f32, end: f32) -> ActiveBetween<Self> {
+        ActiveBetween {
+            force: self,
+            start,
+            end,
+        }
+    }
+
+    /// Ramps the force linearly from zero at `start` to its full strength after `duration`
+    /// seconds, see [`Ramp`].
+    fn ramped(self, start: f32, duration: f32) -> Ramp<Self>
+    where
+        Self: Scalable,
+    {
+        Ramp {
+            force: self,
+            start,
+            duration,
+        }
+    }
+
+    /// Switches the force on for `width` seconds at the beginning of every `period`, see
+    /// [`Pulse`].
+    fn pulsed(self, period: f32, width: f32) -> Pulse<Self> {
+        Pulse {
+            force: self,
+            period,
+            width,
+        }
+    }
+
+    /// Multiplies the force by a sine wave of `frequency` hertz and `phase` radians, see
+    /// [`Sinusoidal`].
+    fn oscillating(self, frequency: f32, phase: f32) -> Sinusoidal<Self>
+    where
+        Self: Scalable,
+    {
+        Sinusoidal {
+            force: self,
+            frequency,
+            phase,
+        }
+    }
+
+    /// Multiplies the force by the factor returned by `modulation` for the simulation time, see
+    /// [`Modulated`].
+    fn modulated<M: Fn(f32) -> f32>(self, modulation: M) -> Modulated<Self, M>
+    where
+        Self: Scalable,
+    {
+        Modulated {
+            force: self,
+            modulation,
+        }
+    }
+}
+
+impl<T: TimedForce> ForceExt for T {}
+
+/// A marker for forces that only add to the accumulated forces, torques, impulses and
+/// accelerations of the objects, which is what lets the combinators scale them.
+///
+/// Custom forces opt in by implementing it, forces that change the position or velocity of the
+/// objects directly must not.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::{ForceExt, Scalable}, force::Force},
+/// #     obj::obj_2d::{Object2d, Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
+/// struct Thrust;
+///
+/// impl Force for Thrust {
+///     fn apply_2d(&self, obj: &mut Object2d) {
+///         obj.add_force(vec2(0.0, -4.0));
+///     }
+/// }
+///
+/// impl Scalable for Thrust {}
+///
+/// let mut obj = Object2dBuilder::new().build();
+/// obj.apply_at(&Thrust.scaled(0.5), 0.0);
+/// assert_eq!(obj.force, vec2(0.0, -2.0));
+/// ```
+pub trait Scalable {}
+
+/// The sum of two forces, created with [`ForceExt::and`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct And<A, B> {
+    pub first: A,
+    pub second: B,
+}
+
+impl<A: TimedForce, B: TimedForce> TimedForce for And<A, B> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        self.first.apply_2d_at(obj, time);
+        self.second.apply_2d_at(obj, time);
+    }
+}
+
+impl<A: Scalable, B: Scalable> Scalable for And<A, B> {}
+
+/// A force multiplied by a constant factor, created with [`ForceExt::scaled`].
+///
+/// Only what the inner force adds to the accumulated forces, torques, impulses and
+/// accelerations of the object is scaled, see [scaling](ForceExt#scaling).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scaled<F> {
+    pub force: F,
+    pub factor: f32,
+}
+
+impl<F: TimedForce + Scalable> TimedForce for Scaled<F> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        apply_scaled(obj, self.factor, |obj| self.force.apply_2d_at(obj, time));
+    }
+}
+
+impl<F: Scalable> Scalable for Scaled<F> {}
+
+/// A force that is only applied during a time window, created with
+/// [`ForceExt::active_between`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActiveBetween<F> {
+    pub force: F,
+    pub start: f32,
+    pub end: f32,
+}
+
+impl<F: TimedForce> TimedForce for ActiveBetween<F> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        if (self.start..self.end).contains(&time) {
+            self.force.apply_2d_at(obj, time);
+        }
+    }
+}
+
+impl<F: Scalable> Scalable for ActiveBetween<F> {}
+
+/// Applies a force through `apply` and multiplies what it added to the accumulators of the
+/// object by `factor`.
+pub(crate) fn apply_scaled<A: FnOnce(&mut Object2d)>(obj: &mut Object2d, factor: f32, apply: A) {
+    let (force, torque, impulse) = (obj.force, obj.torque, obj.impulse);
+    let acceleration = obj.added_acceleration;
+    apply(obj);
+    obj.force = force + (obj.force - force) * factor;
+    obj.torque = torque + (obj.torque - torque) * factor;
+    obj.impulse = impulse + (obj.impulse - impulse) * factor;
+    obj.added_acceleration = acceleration + (obj.added_acceleration - acceleration) * factor;
+}
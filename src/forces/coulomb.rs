@@ -0,0 +1,108 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::pair::{apply_pairwise, inverse_square, PairForce};
+
+/// The Coulomb constant `k = 1 / (4·π·ε₀)` in `N·m²·C⁻²`.
+pub const COULOMB_CONSTANT: f32 = 8.987_552e9;
+
+/// Represents the electrostatic force between charged objects, `k·q1·q2/r²`.
+///
+/// Charges with the same sign repel each other and charges with opposite signs attract each
+/// other, objects without `charge` are not affected. Like [`Attraction`](super::attraction::Attraction),
+/// a softening length `ε` keeps the force finite when two charges get very close.
+///
+/// # Fields
+///
+/// - `constant`: The Coulomb constant `k`, simulations usually pick their own scale.
+/// - `softening`: The softening length `ε`.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{coulomb::coulomb, pair::PairForce},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut charges = [
+///     Object2dBuilder::new().mass(1.0).charge(1.0).build(),
+///     Object2dBuilder::new().mass(1.0).charge(1.0).position(vec2(1.0, 0.0)).build(),
+///     Object2dBuilder::new().mass(1.0).charge(-1.0).position(vec2(0.0, 1.0)).build(),
+/// ];
+/// let electric = coulomb(1.0, 0.0);
+///
+/// // Same sign charges repel and opposite charges attract, with equal and opposite forces.
+/// let repulsion = electric.force_between(&charges[0], &charges[1]);
+/// let attraction = electric.force_between(&charges[0], &charges[2]);
+/// assert_eq!(repulsion, vec2(-1.0, 0.0));
+/// assert_eq!(attraction, vec2(0.0, 1.0));
+/// assert_eq!(electric.force_between(&charges[1], &charges[0]), -repulsion);
+/// assert_eq!(electric.force_between(&charges[2], &charges[0]), -attraction);
+///
+/// electric.apply_direct(&mut charges);
+/// let total = charges[0].force + charges[1].force + charges[2].force;
+/// assert!(total.magnitude() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coulomb {
+    pub constant: f32,
+    pub softening: f32,
+}
+
+impl Coulomb {
+    /// Computes the electric field that a charge located at `source` causes at `position`.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: The point where the field is measured.
+    /// - `source`: The position of the charge.
+    /// - `charge`: The charge causing the field.
+    #[must_use]
+    pub fn field_at(&self, position: Vec2d, source: Vec2d, charge: f32) -> Vec2d {
+        -inverse_square(position, source, self.softening) * (self.constant * charge)
+    }
+
+    /// Adds the electrostatic force between every pair of objects to their accumulated forces.
+    ///
+    /// This is the direct `O(N²)` sum of [`apply_pairwise`], only dynamic objects are pushed.
+    ///
+    /// # Parameters
+    ///
+    /// - `objects`: The charged objects.
+    pub fn apply_direct(&self, objects: &mut [Object2d]) {
+        apply_pairwise(self, objects);
+    }
+}
+
+impl PairForce for Coulomb {
+    /// Computes the electrostatic force that `other` exerts on `obj`.
+    fn force_between(&self, obj: &Object2d, other: &Object2d) -> Vec2d {
+        self.field_at(obj.vec, other.vec, other.charge) * obj.charge
+    }
+}
+
+/// Creates a new `Coulomb` instance.
+///
+/// # Parameters
+///
+/// - `constant`: The Coulomb constant `k`.
+/// - `softening`: The softening length `ε`.
+///
+/// # Returns
+///
+/// A new `Coulomb` instance.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::coulomb::{coulomb, COULOMB_CONSTANT};
+/// let electric = coulomb(COULOMB_CONSTANT, 1e-3);
+/// ```
+#[inline]
+#[must_use]
+pub fn coulomb(constant: f32, softening: f32) -> Coulomb {
+    Coulomb {
+        constant,
+        softening,
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{combinator::Scalable, force::Force};
+
+/// Density of air at sea level and 15 °C in kilograms per cubic meter.
+pub const AIR_DENSITY: f32 = 1.225;
+
+/// Density of fresh water in kilograms per cubic meter.
+pub const WATER_DENSITY: f32 = 1000.0;
+
+/// The relation between the speed of an object and the drag it suffers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragModel {
+    /// Stokes drag, proportional to the velocity: `F = -C·ρ·A·v`. Suited for slow or tiny objects.
+    Linear,
+    /// Newtonian drag, proportional to the square of the speed: `F = -½·C·ρ·A·|v|·v`.
+    Quadratic,
+}
+
+/// Represents the drag an object suffers while moving through a fluid.
+///
+/// The cross-sectional area `A` is taken from the shape of the object, see
+/// [`Object2d::cross_section`], so objects without a shape suffer no drag.
+///
+/// # Fields
+///
+/// - `model`: Whether the drag grows linearly or quadratically with the speed.
+/// - `coefficient`: The drag coefficient `C`, around `0.47` for a sphere and `1.05` for a cube.
+/// - `fluid_density`: The density `ρ` of the fluid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Drag {
+    pub model: DragModel,
+    pub coefficient: f32,
+    pub fluid_density: f32,
+}
+
+impl Drag {
+    /// Computes the drag force acting on an object moving with `velocity`.
+    ///
+    /// # Parameters
+    ///
+    /// - `velocity`: The velocity of the object relative to the fluid.
+    /// - `area`: The cross-sectional area of the object facing the fluid.
+    ///
+    /// # Returns
+    ///
+    /// The drag force, opposite to `velocity`.
+    #[must_use]
+    pub fn force(&self, velocity: Vec2d, area: f32) -> Vec2d {
+        let factor = self.coefficient * self.fluid_density * area;
+        match self.model {
+            DragModel::Linear => -velocity * factor,
+            DragModel::Quadratic => -velocity * (0.5 * factor * velocity.magnitude()),
+        }
+    }
+}
+
+impl Force for Drag {
+    /// Adds the drag force to a 2D object.
+    ///
+    /// Only dynamic objects are affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::shape::Shape,
+    /// #     forces::drag::quadratic_drag,
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut ball = Object2dBuilder::new()
+    ///     .mass(1.0)
+    ///     .radius(0.5)
+    ///     .shape(Shape::Circle)
+    ///     .velocity(vec2(2.0, 0.0))
+    ///     .build();
+    /// ball.apply(&quadratic_drag(0.5, 2.0));
+    ///
+    /// assert_eq!(ball.force, vec2(-2.0, 0.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The 2D object to which the force is applied.
+    fn apply_2d(&self, obj: &mut Object2d) {
+        if !obj.body_type.is_dynamic() {
+            return;
+        }
+        let area = obj.cross_section(obj.velocity);
+        obj.add_force(self.force(obj.velocity, area));
+    }
+}
+
+impl Scalable for Drag {}
+
+/// Creates a new linear (Stokes) `Drag` instance.
+///
+/// # Parameters
+///
+/// - `coefficient`: The drag coefficient.
+/// - `fluid_density`: The density of the fluid.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::drag::{linear_drag, AIR_DENSITY};
+/// let drag = linear_drag(0.47, AIR_DENSITY);
+/// ```
+#[inline]
+#[must_use]
+pub fn linear_drag(coefficient: f32, fluid_density: f32) -> Drag {
+    Drag {
+        model: DragModel::Linear,
+        coefficient,
+        fluid_density,
+    }
+}
+
+/// Creates a new quadratic (Newtonian) `Drag` instance.
+///
+/// # Parameters
+///
+/// - `coefficient`: The drag coefficient.
+/// - `fluid_density`: The density of the fluid.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::drag::{quadratic_drag, AIR_DENSITY};
+/// let drag = quadratic_drag(0.47, AIR_DENSITY);
+/// ```
+#[inline]
+#[must_use]
+pub fn quadratic_drag(coefficient: f32, fluid_density: f32) -> Drag {
+    Drag {
+        model: DragModel::Quadratic,
+        coefficient,
+        fluid_density,
+    }
+}
@@ -0,0 +1,133 @@
+use crate::{collision::ray::Ray2d, obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+/// Represents a blast that pushes away every object around a point with an impulse.
+///
+/// The impulse is `strength` at the center and fades linearly to zero at `radius`. When the
+/// explosion is `occluded`, a ray is cast from the center towards each object and objects hidden
+/// behind others are not pushed.
+///
+/// # Fields
+///
+/// - `center`: The point where the explosion happens.
+/// - `strength`: The impulse received by an object at the center.
+/// - `radius`: The distance at which the impulse vanishes.
+/// - `occluded`: Whether objects can shelter others from the blast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Explosion {
+    pub center: Vec2d,
+    pub strength: f32,
+    pub radius: f32,
+    pub occluded: bool,
+}
+
+impl Explosion {
+    /// Computes the impulse the explosion gives to an object.
+    ///
+    /// Only dynamic objects inside the radius are pushed, an object exactly at the center is not
+    /// pushed since the blast has no direction there.
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The object receiving the impulse.
+    /// - `occluders`: The objects that may hide `obj` from the blast, ignored unless `occluded` is set.
+    ///
+    /// # Returns
+    ///
+    /// The impulse pointing away from the center.
+    #[must_use]
+    pub fn impulse_on<'a, I>(&self, obj: &Object2d, occluders: I) -> Vec2d
+    where
+        I: IntoIterator<Item = &'a Object2d>,
+    {
+        if !obj.body_type.is_dynamic() {
+            return Vec2d::ZERO;
+        }
+        let offset = self.center.component(obj.vec);
+        let distance = offset.magnitude();
+        if distance == 0.0 || distance >= self.radius {
+            return Vec2d::ZERO;
+        }
+        if self.occluded {
+            let ray = Ray2d::between(self.center, obj.vec);
+            let exposed = ray.cast(obj).map_or(distance, |hit| hit.distance);
+            let hidden = occluders
+                .into_iter()
+                .filter_map(|occluder| ray.cast(occluder))
+                .any(|hit| hit.distance < exposed);
+            if hidden {
+                return Vec2d::ZERO;
+            }
+        }
+        offset / distance * (self.strength * (1.0 - distance / self.radius))
+    }
+
+    /// Adds the impulse of the explosion to every object of a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::shape::Shape,
+    /// #     forces::explosion::explosion,
+    /// #     obj::{body_type::BodyType, obj_2d::Object2dBuilder},
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut objects = [
+    ///     Object2dBuilder::new().mass(1.0).position(vec2(2.0, 0.0)).build(),
+    ///     Object2dBuilder::new().mass(1.0).position(vec2(-6.0, 0.0)).build(),
+    ///     Object2dBuilder::new()
+    ///         .position(vec2(-4.0, 0.0))
+    ///         .shape(Shape::AABB(vec2(0.0, -1.0), vec2(1.0, 1.0)))
+    ///         .body_type(BodyType::Static)
+    ///         .build(),
+    /// ];
+    ///
+    /// let mut blast = explosion(vec2(0.0, 0.0), 10.0, 8.0);
+    /// blast.apply(&mut objects);
+    /// assert_eq!(objects[0].impulse, vec2(7.5, 0.0));
+    /// assert_eq!(objects[1].impulse, vec2(-2.5, 0.0));
+    ///
+    /// objects[1].clear_accumulators();
+    /// blast.occluded = true;
+    /// blast.apply(&mut objects);
+    /// // The wall shelters the second object.
+    /// assert_eq!(objects[1].impulse, vec2(0.0, 0.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `objects`: The objects hit by the explosion, they also occlude each other.
+    pub fn apply(&self, objects: &mut [Object2d]) {
+        for index in 0..objects.len() {
+            let occluders = objects
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, obj)| obj);
+            let impulse = self.impulse_on(&objects[index], occluders);
+            objects[index].add_impulse(impulse);
+        }
+    }
+}
+
+/// Creates a new `Explosion` instance that is not occluded.
+///
+/// # Parameters
+///
+/// - `center`: The point where the explosion happens.
+/// - `strength`: The impulse received by an object at the center.
+/// - `radius`: The distance at which the impulse vanishes.
+///
+/// # Returns
+///
+/// A new `Explosion` instance.
+#[inline]
+#[must_use]
+pub fn explosion(center: Vec2d, strength: f32, radius: f32) -> Explosion {
+    Explosion {
+        center,
+        strength,
+        radius,
+        occluded: false,
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{combinator::Scalable, force::Force};
+
+/// A trait for force fields, forces that only depend on where they are sampled.
+///
+/// Every force field is also a [`Force`]: applying it to an object samples the field at the
+/// position of the object and adds the result to its accumulated forces. Only dynamic objects
+/// are affected.
+///
+/// # Examples
+///
+/// Implementing the `ForceField` trait for a custom field:
+///
+/// ```
+/// # use motion::{forces::field::ForceField, obj::obj_2d::Object2dBuilder, vec::vec_2d::{vec2, Vec2d}};
+/// struct Conveyor;
+///
+/// impl ForceField for Conveyor {
+///     fn sample(&self, position: Vec2d) -> Vec2d {
+///         vec2(if position.y > 0.0 { 1.0 } else { -1.0 }, 0.0)
+///     }
+/// }
+///
+/// let mut obj = Object2dBuilder::new().position(vec2(0.0, 2.0)).build();
+/// obj.apply(&Conveyor);
+/// assert_eq!(obj.force, vec2(1.0, 0.0));
+/// ```
+pub trait ForceField {
+    /// Returns the force the field exerts at `position`.
+    fn sample(&self, position: Vec2d) -> Vec2d;
+
+    /// Restricts the field to the axis-aligned region between `min` and `max`, it is zero outside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{forces::field::{uniform, ForceField}, vec::vec_2d::vec2};
+    /// let wind = uniform(vec2(3.0, 0.0)).within(vec2(0.0, 0.0), vec2(10.0, 10.0));
+    /// assert_eq!(wind.sample(vec2(5.0, 5.0)), vec2(3.0, 0.0));
+    /// assert_eq!(wind.sample(vec2(15.0, 5.0)), vec2(0.0, 0.0));
+    /// ```
+    fn within(self, min: Vec2d, max: Vec2d) -> Region<Self>
+    where
+        Self: Sized,
+    {
+        Region {
+            field: self,
+            min,
+            max,
+        }
+    }
+}
+
+impl<T: ForceField> Force for T {
+    fn apply_2d(&self, obj: &mut Object2d) {
+        if obj.body_type.is_dynamic() {
+            obj.add_force(self.sample(obj.vec));
+        }
+    }
+}
+
+impl<T: ForceField> Scalable for T {}
+
+/// A field with the same force everywhere, like a constant wind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform {
+    pub force: Vec2d,
+}
+
+impl ForceField for Uniform {
+    fn sample(&self, _position: Vec2d) -> Vec2d {
+        self.force
+    }
+}
+
+/// A field pushing away from a center, or pulling towards it when `strength` is negative.
+///
+/// The force is `strength` at the center and fades linearly to zero at `radius`, it is zero
+/// beyond `radius` and at the center itself, where it has no direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    pub center: Vec2d,
+    pub strength: f32,
+    pub radius: f32,
+}
+
+impl ForceField for Radial {
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{forces::field::{radial, ForceField}, vec::vec_2d::vec2};
+    /// let blast = radial(vec2(0.0, 0.0), 10.0, 4.0);
+    /// assert_eq!(blast.sample(vec2(0.0, 1.0)), vec2(0.0, 7.5));
+    /// assert_eq!(blast.sample(vec2(5.0, 0.0)), vec2(0.0, 0.0));
+    ///
+    /// let attractor = radial(vec2(0.0, 0.0), -10.0, 4.0);
+    /// assert_eq!(attractor.sample(vec2(2.0, 0.0)), vec2(-5.0, 0.0));
+    /// ```
+    fn sample(&self, position: Vec2d) -> Vec2d {
+        let offset = self.center.component(position);
+        let distance = offset.magnitude();
+        if distance == 0.0 || distance >= self.radius {
+            return Vec2d::ZERO;
+        }
+        offset / distance * (self.strength * (1.0 - distance / self.radius))
+    }
+}
+
+/// A field swirling around a center, turning from the `+x` axis towards the `+y` axis for a
+/// positive `strength` and the other way around for a negative one.
+///
+/// The force is perpendicular to the direction from the center, its magnitude fades linearly from
+/// `strength` at the center to zero at `radius`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vortex {
+    pub center: Vec2d,
+    pub strength: f32,
+    pub radius: f32,
+}
+
+impl ForceField for Vortex {
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{forces::field::{vortex, ForceField}, vec::vec_2d::vec2};
+    /// let whirlpool = vortex(vec2(0.0, 0.0), 4.0, 2.0);
+    /// assert_eq!(whirlpool.sample(vec2(1.0, 0.0)), vec2(0.0, 2.0));
+    /// assert_eq!(whirlpool.sample(vec2(0.0, 1.0)), vec2(-2.0, 0.0));
+    /// ```
+    fn sample(&self, position: Vec2d) -> Vec2d {
+        let offset = self.center.component(position);
+        let distance = offset.magnitude();
+        if distance == 0.0 || distance >= self.radius {
+            return Vec2d::ZERO;
+        }
+        let tangent = Vec2d::new(-offset.y, offset.x) / distance;
+        tangent * (self.strength * (1.0 - distance / self.radius))
+    }
+}
+
+/// A field defined by a user function mapping positions to forces.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldFn<F>(pub F);
+
+impl<F: Fn(Vec2d) -> Vec2d> ForceField for FieldFn<F> {
+    fn sample(&self, position: Vec2d) -> Vec2d {
+        (self.0)(position)
+    }
+}
+
+/// A field restricted to an axis-aligned region, created with [`ForceField::within`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Region<F> {
+    pub field: F,
+    pub min: Vec2d,
+    pub max: Vec2d,
+}
+
+impl<F: ForceField> ForceField for Region<F> {
+    fn sample(&self, position: Vec2d) -> Vec2d {
+        let inside = (self.min.x..=self.max.x).contains(&position.x)
+            && (self.min.y..=self.max.y).contains(&position.y);
+        if inside {
+            self.field.sample(position)
+        } else {
+            Vec2d::ZERO
+        }
+    }
+}
+
+/// Creates a new `Uniform` field.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{forces::field::uniform, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+/// let mut kite = Object2dBuilder::new().mass(1.0).build();
+/// kite.apply(&uniform(vec2(2.0, 0.0)));
+/// assert_eq!(kite.force, vec2(2.0, 0.0));
+/// ```
+#[inline]
+#[must_use]
+pub fn uniform(force: Vec2d) -> Uniform {
+    Uniform { force }
+}
+
+/// Creates a new `Radial` field.
+///
+/// # Parameters
+///
+/// - `center`: The center of the field.
+/// - `strength`: The force at the center, negative to attract.
+/// - `radius`: The distance at which the force vanishes.
+#[inline]
+#[must_use]
+pub fn radial(center: Vec2d, strength: f32, radius: f32) -> Radial {
+    Radial {
+        center,
+        strength,
+        radius,
+    }
+}
+
+/// Creates a new `Vortex` field.
+///
+/// # Parameters
+///
+/// - `center`: The center of the field.
+/// - `strength`: The force at the center, negative to swirl the other way around.
+/// - `radius`: The distance at which the force vanishes.
+#[inline]
+#[must_use]
+pub fn vortex(center: Vec2d, strength: f32, radius: f32) -> Vortex {
+    Vortex {
+        center,
+        strength,
+        radius,
+    }
+}
+
+/// Creates a new field from a function mapping positions to forces.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{forces::field::field_fn, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+/// let slope = field_fn(|position| vec2(0.0, position.x));
+///
+/// let mut obj = Object2dBuilder::new().position(vec2(3.0, 0.0)).build();
+/// obj.apply(&slope);
+/// assert_eq!(obj.force, vec2(0.0, 3.0));
+/// ```
+#[inline]
+pub fn field_fn<F: Fn(Vec2d) -> Vec2d>(function: F) -> FieldFn<F> {
+    FieldFn(function)
+}
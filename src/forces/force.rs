@@ -2,19 +2,32 @@ use crate::obj::obj_2d::Object2d;
 
 /// A trait for applying forces to 2D objects.
 ///
+/// Implementors should prefer adding to the accumulators of the object with
+/// [`Object2d::add_force`], [`Object2d::add_impulse`] or [`Object2d::add_force_at_point`]
+/// instead of changing its position or velocity, so the result does not depend on the
+/// order in which forces are applied and they are all consumed by [`Object2d::integrate`].
+///
 /// # Examples
 ///
 /// Implementing the `Force` trait for a custom force:
 ///
 /// ```
+/// # use motion::{
+/// #     forces::force::Force,
+/// #     obj::obj_2d::{Object2d, Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// # };
 /// struct Gravity;
 ///
 /// impl Force for Gravity {
-///     fn apply_2d(&mut self, obj: &Object2d) {
-///         // Apply gravitational force to obj
+///     fn apply_2d(&self, obj: &mut Object2d) {
+///         obj.add_force(vec2(0.0, 9.807) * obj.mass);
 ///     }
 /// }
 ///
+/// let mut obj = Object2dBuilder::new().mass(2.0).build();
+/// obj.apply(&Gravity);
+/// assert_eq!(obj.force, vec2(0.0, 19.614));
 /// ```
 ///
 /// # Methods
@@ -23,3 +36,19 @@ use crate::obj::obj_2d::Object2d;
 pub trait Force {
     fn apply_2d(&self, obj: &mut Object2d);
 }
+
+/// A trait for applying forces that depend on the simulation time to 2D objects.
+///
+/// Every [`Force`] is a `TimedForce` that ignores the time. Forces that change over time, like
+/// the ones built with [`ForceExt`](super::combinator::ForceExt), only implement `TimedForce`, so
+/// they cannot be applied without a time, apply them with [`Object2d::apply_at`] instead.
+pub trait TimedForce {
+    /// Applies the force as it is at `time` seconds of simulation.
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32);
+}
+
+impl<T: Force> TimedForce for T {
+    fn apply_2d_at(&self, obj: &mut Object2d, _time: f32) {
+        self.apply_2d(obj);
+    }
+}
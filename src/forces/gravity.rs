@@ -1,56 +1,67 @@
-use crate::obj::obj_2d::Object2d;
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
 
-use super::force::Force;
+use super::{combinator::Scalable, force::Force};
 
-/// Constant for Earth's gravity in meters per second squared.
-pub const EARTH_GRAVITY: f32 = -9.807;
+/// Earth's gravity in meters per second squared.
+///
+/// Motion uses screen coordinates where `y` grows downwards, so the preset points towards `+y`.
+pub const EARTH_GRAVITY: Vec2d = Vec2d { x: 0.0, y: 9.807 };
 
-/// Represents the gravitational force applied to an object.
+/// Represents a uniform gravitational field applied to an object.
 ///
 /// # Fields
 ///
-/// - `force`: The gravitational force.
-/// - `time`: The time over which the force is applied.
-/// - `delta_time`: The time step for the simulation.
-#[derive(Debug)]
+/// - `acceleration`: The acceleration of the gravitational field.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Gravity {
-    pub force: f32,
-    pub time: f32,
-    pub delta_time: f32,
+    pub acceleration: Vec2d,
 }
 
 impl Force for Gravity {
     /// Applies the gravitational force to a 2D object.
     ///
+    /// The acceleration is scaled by the `gravity_scale` of the object and added to its
+    /// accumulated accelerations, see [`Object2d::add_acceleration`], so it takes effect on the
+    /// next integration step and objects of any mass, even without one, fall the same.
+    /// Only dynamic objects are affected, static and kinematic objects ignore gravity.
+    ///
     /// # Examples
     ///
     /// ```
-    /// let mut obj = Object2d::new(/* initial parameters */);
+    /// # use motion::{
+    /// #     forces::gravity::{Gravity, EARTH_GRAVITY},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new().mass(2.0).build();
     /// let gravity = Gravity {
-    ///     force: EARTH_GRAVITY,
-    ///     time: 1.0,
-    ///     delta_time: 0.016,
+    ///     acceleration: EARTH_GRAVITY,
     /// };
-    /// gravity.apply_2d(&mut obj);
+    /// obj.apply(&gravity);
+    /// assert_eq!(obj.added_acceleration, vec2(0.0, 9.807));
+    ///
+    /// obj.integrate(1.0);
+    /// assert_eq!(obj.velocity, EARTH_GRAVITY);
     /// ```
     ///
     /// # Parameters
     ///
     /// - `obj`: The 2D object to which the force is applied.
     fn apply_2d(&self, obj: &mut Object2d) {
-        obj.vec.y += obj.velocity.y * self.delta_time
-            - 0.5 * self.force * (self.delta_time * self.delta_time);
-        obj.velocity.y -= self.force * self.delta_time;
+        if !obj.body_type.is_dynamic() {
+            return;
+        }
+        obj.add_acceleration(self.acceleration * obj.gravity_scale);
     }
 }
 
+impl Scalable for Gravity {}
+
 /// Creates a new `Gravity` instance.
 ///
 /// # Parameters
 ///
-/// - `force`: The gravitational force.
-/// - `time`: The time over which the force is applied.
-/// - `delta_time`: The time step for the simulation.
+/// - `acceleration`: The acceleration of the gravitational field.
 ///
 /// # Returns
 ///
@@ -59,15 +70,72 @@ impl Force for Gravity {
 /// # Examples
 ///
 /// ```
-/// let gravity = gravity(EARTH_GRAVITY, 1.0, 0.016);
+/// # use motion::{forces::gravity::gravity, vec::vec_2d::vec2};
+/// let sideways = gravity(vec2(-9.807, 0.0));
+/// assert_eq!(sideways.acceleration.x, -9.807);
 /// ```
 #[inline]
-pub fn gravity<F: Into<f32>, T: Into<f32>, D: Into<f32>>(
+#[must_use]
+pub fn gravity(acceleration: Vec2d) -> Gravity {
+    Gravity { acceleration }
+}
+
+/// Earth's gravity as the signed scalar used by [`ScalarGravity`], pulling towards `+y`.
+#[deprecated(since = "0.2.0", note = "Use EARTH_GRAVITY, now a Vec2d, instead")]
+pub const EARTH_GRAVITY_SCALAR: f32 = -9.807;
+
+/// The gravity of previous versions, which moved the object along `y` by itself instead of
+/// going through the integration step.
+///
+/// # Fields
+///
+/// - `force`: The gravitational force.
+/// - `time`: The time over which the force is applied.
+/// - `delta_time`: The time step for the simulation.
+#[deprecated(
+    since = "0.2.0",
+    note = "Use Gravity with a vector acceleration instead"
+)]
+#[derive(Debug)]
+pub struct ScalarGravity {
+    pub force: f32,
+    pub time: f32,
+    pub delta_time: f32,
+}
+
+#[allow(deprecated)]
+impl Force for ScalarGravity {
+    /// Moves the object and changes its velocity along `y` by the gravity over `delta_time`.
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The 2D object to which the force is applied.
+    fn apply_2d(&self, obj: &mut Object2d) {
+        obj.vec.y += obj.velocity.y * self.delta_time
+            - 0.5 * self.force * (self.delta_time * self.delta_time);
+        obj.velocity.y -= self.force * self.delta_time;
+    }
+}
+
+/// Creates a new `ScalarGravity` instance, like `gravity` did in previous versions.
+///
+/// # Parameters
+///
+/// - `force`: The gravitational force.
+/// - `time`: The time over which the force is applied.
+/// - `delta_time`: The time step for the simulation.
+#[deprecated(
+    since = "0.2.0",
+    note = "Use gravity with a vector acceleration instead"
+)]
+#[inline]
+#[allow(deprecated)]
+pub fn scalar_gravity<F: Into<f32>, T: Into<f32>, D: Into<f32>>(
     force: F,
     time: T,
     delta_time: D,
-) -> Gravity {
-    Gravity {
+) -> ScalarGravity {
+    ScalarGravity {
         force: force.into(),
         time: time.into(),
         delta_time: delta_time.into(),
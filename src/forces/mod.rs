@@ -1,3 +1,15 @@
+pub mod attraction;
+#[cfg(feature = "alloc")]
+pub mod barnes_hut;
+pub mod buoyancy;
+pub mod combinator;
+pub mod coulomb;
+pub mod drag;
+pub mod explosion;
+pub mod field;
 pub mod force;
 pub mod gravity;
 pub mod r#move;
+pub mod pair;
+pub mod spring;
+pub mod timed;
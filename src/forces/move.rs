@@ -1,6 +1,93 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
 use super::force::Force;
 
+/// Represents a kinematic 2D movement over a time step.
+///
+/// Applying it advances the position and velocity of an object on both axes with the equations
+/// of uniformly accelerated motion, `x' = x + v·dt + ½·a·dt²` and `v' = v + a·dt`, where `a` is
+/// the `acceleration` of the object.
+///
+/// # Fields
+///
+/// - `delta_time`: The time step in seconds, usually the `delta_time` of the event loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Move2d {
+    pub delta_time: f32,
+}
+
+impl Force for Move2d {
+    /// Applies the movement to a 2D object.
+    ///
+    /// Static objects never move and kinematic objects move only by their velocity,
+    /// ignoring their acceleration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::r#move::Move2d,
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new()
+    ///     .velocity(vec2(2.0, 0.0))
+    ///     .acceleration(vec2(0.0, 4.0))
+    ///     .build();
+    /// let move_force = Move2d { delta_time: 0.5 };
+    /// obj.apply(&move_force);
+    ///
+    /// assert_eq!(obj.vec, vec2(1.0, 0.5));
+    /// assert_eq!(obj.velocity, vec2(2.0, 2.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The 2D object to which the force is applied.
+    fn apply_2d(&self, obj: &mut Object2d) {
+        if obj.body_type.is_static() {
+            return;
+        }
+        let time = self.delta_time;
+        let acceleration = if obj.body_type.is_kinematic() {
+            Vec2d::ZERO
+        } else {
+            obj.acceleration
+        };
+        obj.vec += obj.velocity * time + acceleration * (0.5 * time * time);
+        obj.velocity += acceleration * time;
+    }
+}
+
+/// Creates a new `Move2d` instance.
+///
+/// # Parameters
+///
+/// - `delta_time`: The time step in seconds.
+///
+/// # Returns
+///
+/// A new `Move2d` instance.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::r#move::move2d;
+/// let move_force = move2d(1.0 / 60.0);
+/// assert_eq!(move_force.delta_time, 1.0 / 60.0);
+/// ```
+#[inline]
+pub fn move2d<DT: Into<f32>>(delta_time: DT) -> Move2d {
+    Move2d {
+        delta_time: delta_time.into(),
+    }
+}
+
 /// Represents the direction in a 2D plane.
+#[deprecated(
+    since = "0.2.0",
+    note = "Use Move2d, which moves on both axes, instead"
+)]
 #[derive(Debug, PartialEq, Eq)]
 pub enum Direction2d {
     /// Movement along the x-axis.
@@ -9,76 +96,70 @@ pub enum Direction2d {
     Y,
 }
 
-/// Represents a 2D movement with specified direction, delta time, and frames per second.
+/// The movement of previous versions, along a single axis over `fps * delta_time` seconds and
+/// without changing the velocity.
 ///
 /// # Fields
 ///
 /// - `direction`: The direction of the movement (X or Y).
 /// - `delta_time`: The time step for the simulation.
 /// - `fps`: The frames per second for the simulation.
-pub struct Move2d {
+#[deprecated(
+    since = "0.2.0",
+    note = "Use Move2d, which moves on both axes, instead"
+)]
+#[allow(deprecated)]
+#[derive(Debug)]
+pub struct AxisMove2d {
     pub direction: Direction2d,
     pub delta_time: f32,
     pub fps: f32,
 }
 
-impl Force for Move2d {
-    /// Applies the movement force to a 2D object based on the direction.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// let mut obj = Object2d::new(/* initial parameters */);
-    /// let move_force = Move2d {
-    ///     direction: Direction2d::X,
-    ///     delta_time: 0.016,
-    ///     fps: 60.0,
-    /// };
-    /// move_force.apply_2d(&mut obj);
-    /// ```
+#[allow(deprecated)]
+impl Force for AxisMove2d {
+    /// Moves the object along `direction` by its velocity and acceleration on that axis.
     ///
     /// # Parameters
     ///
     /// - `obj`: The 2D object to which the force is applied.
-    fn apply_2d(&self, obj: &mut crate::obj::obj_2d::Object2d) {
+    fn apply_2d(&self, obj: &mut Object2d) {
+        if obj.body_type.is_static() {
+            return;
+        }
         let time = self.fps * self.delta_time;
+        let acceleration = if obj.body_type.is_kinematic() {
+            Vec2d::ZERO
+        } else {
+            obj.acceleration
+        };
         match self.direction {
             Direction2d::Y => {
-                obj.vec.y =
-                    obj.vec.y + obj.velocity.y * time + 0.5 * obj.acceleration.y * (time * time);
+                obj.vec.y += obj.velocity.y * time + 0.5 * acceleration.y * (time * time);
             }
             Direction2d::X => {
-                obj.vec.x =
-                    obj.vec.x + obj.velocity.x * time + 0.5 * obj.acceleration.x * (time * time);
+                obj.vec.x += obj.velocity.x * time + 0.5 * acceleration.x * (time * time);
             }
         }
     }
 }
 
-/// Creates a new `Move2d` instance.
+/// Creates a new `AxisMove2d` instance, like `move2d` did in previous versions.
 ///
 /// # Parameters
 ///
 /// - `direction`: The direction of the movement (X or Y).
 /// - `delta_time`: The time step for the simulation.
 /// - `fps`: The frames per second for the simulation.
-///
-/// # Returns
-///
-/// A new `Move2d` instance.
-///
-/// # Examples
-///
-/// ```
-/// let move_force = move2d(Direction2d::X, 0.016, 60.0);
-/// ```
+#[deprecated(since = "0.2.0", note = "Use move2d with a delta time instead")]
 #[inline]
-pub fn move2d<DT: Into<f32>, F: Into<f32>>(
+#[allow(deprecated)]
+pub fn axis_move2d<DT: Into<f32>, F: Into<f32>>(
     direction: Direction2d,
     delta_time: DT,
     fps: F,
-) -> Move2d {
-    Move2d {
+) -> AxisMove2d {
+    AxisMove2d {
         direction,
         delta_time: delta_time.into(),
         fps: fps.into(),
@@ -0,0 +1,106 @@
+use crate::{formulas::sqrt::sqrt, obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+/// A trait for forces that act between two 2D objects.
+///
+/// Implementors only describe the force one object receives from the other, the trait applies
+/// it to both objects as an equal and opposite pair, so momentum is conserved.
+///
+/// # Examples
+///
+/// Implementing the `PairForce` trait for a custom force:
+///
+/// ```
+/// # use motion::{forces::pair::PairForce, obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+/// struct Rubber;
+///
+/// impl PairForce for Rubber {
+///     fn force_between(&self, obj: &Object2d, other: &Object2d) -> Vec2d {
+///         obj.vec.component(other.vec)
+///     }
+/// }
+/// ```
+pub trait PairForce {
+    /// Computes the force that `other` exerts on `obj`.
+    fn force_between(&self, obj: &Object2d, other: &Object2d) -> Vec2d;
+
+    /// Adds the force to the accumulators of both objects, `obj2` receives the opposite of the force on `obj1`.
+    ///
+    /// Only dynamic objects are pushed.
+    fn apply_pair_2d(&self, obj1: &mut Object2d, obj2: &mut Object2d) {
+        let force = self.force_between(obj1, obj2);
+        if obj1.body_type.is_dynamic() {
+            obj1.add_force(force);
+        }
+        if obj2.body_type.is_dynamic() {
+            obj2.add_force(-force);
+        }
+    }
+}
+
+/// Applies a pair force between every pair of objects of a slice.
+///
+/// Every pair is visited once, which takes `O(N²)` and does not allocate memory.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{pair::apply_pairwise, spring::spring},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let mut objects = [
+///     Object2dBuilder::new().mass(1.0).build(),
+///     Object2dBuilder::new().mass(1.0).position(vec2(2.0, 0.0)).build(),
+///     Object2dBuilder::new().mass(1.0).position(vec2(4.0, 0.0)).build(),
+/// ];
+///
+/// apply_pairwise(&spring(1.0, 1.0, 0.0), &mut objects);
+///
+/// assert_eq!(objects[0].force, vec2(4.0, 0.0));
+/// assert_eq!(objects[1].force, vec2(0.0, 0.0));
+/// assert_eq!(objects[2].force, vec2(-4.0, 0.0));
+/// ```
+///
+/// # Parameters
+///
+/// - `force`: The force acting between each pair.
+/// - `objects`: The objects the force acts on.
+pub fn apply_pairwise<P: PairForce>(force: &P, objects: &mut [Object2d]) {
+    for i in 0..objects.len() {
+        let (head, tail) = objects.split_at_mut(i + 1);
+        let obj = &mut head[i];
+        for other in tail {
+            force.apply_pair_2d(obj, other);
+        }
+    }
+}
+
+/// Computes the softened inverse square law `d / (|d|² + ε²)^(3/2)` for the offset `d` from `from` to `to`.
+///
+/// The result points from `from` towards `to` and its length is `1 / r²` without softening, the
+/// kernel shared by [`Attraction`](super::attraction::Attraction) and [`Coulomb`](super::coulomb::Coulomb).
+/// Points sharing the same position without softening return the zero vector.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{forces::pair::inverse_square, vec::vec_2d::vec2};
+/// assert_eq!(inverse_square(vec2(0.0, 0.0), vec2(2.0, 0.0), 0.0), vec2(0.25, 0.0));
+/// assert_eq!(inverse_square(vec2(0.0, 0.0), vec2(0.0, 0.0), 1.0), vec2(0.0, 0.0));
+/// ```
+///
+/// # Parameters
+///
+/// - `from`: The point where the law is evaluated.
+/// - `to`: The point the law points towards.
+/// - `softening`: The softening length `ε`.
+#[must_use]
+pub fn inverse_square(from: Vec2d, to: Vec2d, softening: f32) -> Vec2d {
+    let offset = from.component(to);
+    let distance2 = offset * offset + softening * softening;
+    if distance2 == 0.0 {
+        return Vec2d::ZERO;
+    }
+    offset / (distance2 * sqrt(distance2))
+}
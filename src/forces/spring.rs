@@ -0,0 +1,86 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::pair::PairForce;
+
+/// Represents a Hooke spring with a damper linking the positions of two objects.
+///
+/// The spring pulls or pushes the objects with `k·(l - l₀)` towards its rest length, and the
+/// damper opposes their relative velocity along the spring with `c·Δv`.
+///
+/// # Fields
+///
+/// - `rest_length`: The length `l₀` at which the spring exerts no force.
+/// - `stiffness`: The stiffness `k` of the spring.
+/// - `damping`: The damping coefficient `c` of the damper.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    pub rest_length: f32,
+    pub stiffness: f32,
+    pub damping: f32,
+}
+
+impl PairForce for Spring {
+    /// Computes the spring and damper force that `other` exerts on `obj`.
+    ///
+    /// Objects sharing the same position exert no force on each other, since the spring has no direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::{pair::PairForce, spring::spring},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut anchor = Object2dBuilder::new().mass(1.0).build();
+    /// let mut weight = Object2dBuilder::new()
+    ///     .mass(1.0)
+    ///     .position(vec2(0.0, 3.0))
+    ///     .velocity(vec2(0.0, 1.0))
+    ///     .build();
+    ///
+    /// spring(2.0, 10.0, 0.5).apply_pair_2d(&mut anchor, &mut weight);
+    ///
+    /// assert_eq!(anchor.force, vec2(0.0, 10.5));
+    /// assert_eq!(weight.force, vec2(0.0, -10.5));
+    /// ```
+    fn force_between(&self, obj: &Object2d, other: &Object2d) -> Vec2d {
+        let offset = obj.vec.component(other.vec);
+        let length = offset.magnitude();
+        if length == 0.0 {
+            return Vec2d::ZERO;
+        }
+        let direction = offset / length;
+        let stretch = length - self.rest_length;
+        let relative_speed = (other.velocity - obj.velocity) * direction;
+        direction * (self.stiffness * stretch + self.damping * relative_speed)
+    }
+}
+
+/// Creates a new `Spring` instance.
+///
+/// # Parameters
+///
+/// - `rest_length`: The length at which the spring exerts no force.
+/// - `stiffness`: The stiffness of the spring.
+/// - `damping`: The damping coefficient of the damper.
+///
+/// # Returns
+///
+/// A new `Spring` instance.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::forces::spring::spring;
+/// let rope = spring(1.0, 200.0, 5.0);
+/// ```
+#[inline]
+#[must_use]
+pub fn spring(rest_length: f32, stiffness: f32, damping: f32) -> Spring {
+    Spring {
+        rest_length,
+        stiffness,
+        damping,
+    }
+}
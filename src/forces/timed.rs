@@ -0,0 +1,189 @@
+use core::f32::consts::TAU;
+
+use crate::{formulas::sin::sin, obj::obj_2d::Object2d};
+
+use super::{
+    combinator::{apply_scaled, Scalable},
+    force::TimedForce,
+};
+
+/// A force that grows linearly from zero to its full strength, created with
+/// [`ForceExt::ramped`](super::combinator::ForceExt::ramped).
+///
+/// The force is zero before `start` and at full strength from `start + duration` on, a zero
+/// `duration` switches it on at once.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let thrust = uniform(vec2(0.0, -8.0)).ramped(1.0, 2.0);
+/// let mut obj = Object2dBuilder::new().build();
+///
+/// obj.apply_at(&thrust, 2.0);
+/// assert_eq!(obj.force, vec2(0.0, -4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ramp<F> {
+    pub force: F,
+    pub start: f32,
+    pub duration: f32,
+}
+
+impl<F> Ramp<F> {
+    /// Returns the fraction of the force applied at `time`, between 0 and 1.
+    #[must_use]
+    pub fn factor(&self, time: f32) -> f32 {
+        if time < self.start {
+            0.0
+        } else if time >= self.start + self.duration {
+            1.0
+        } else {
+            (time - self.start) / self.duration
+        }
+    }
+}
+
+impl<F: TimedForce + Scalable> TimedForce for Ramp<F> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        let factor = self.factor(time);
+        if factor > 0.0 {
+            apply_scaled(obj, factor, |obj| self.force.apply_2d_at(obj, time));
+        }
+    }
+}
+
+impl<F: Scalable> Scalable for Ramp<F> {}
+
+/// A force switched on for `width` seconds at the beginning of every `period`, created with
+/// [`ForceExt::pulsed`](super::combinator::ForceExt::pulsed).
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let kick = uniform(vec2(5.0, 0.0)).pulsed(1.0, 0.25);
+/// let mut obj = Object2dBuilder::new().build();
+///
+/// obj.apply_at(&kick, 3.5);
+/// assert_eq!(obj.force, vec2(0.0, 0.0));
+///
+/// obj.apply_at(&kick, 4.125);
+/// assert_eq!(obj.force, vec2(5.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pulse<F> {
+    pub force: F,
+    pub period: f32,
+    pub width: f32,
+}
+
+impl<F> Pulse<F> {
+    /// Returns whether the force is switched on at `time`.
+    #[must_use]
+    pub fn is_on(&self, time: f32) -> bool {
+        let mut phase = time % self.period;
+        if phase < 0.0 {
+            phase += self.period;
+        }
+        phase < self.width
+    }
+}
+
+impl<F: TimedForce> TimedForce for Pulse<F> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        if self.is_on(time) {
+            self.force.apply_2d_at(obj, time);
+        }
+    }
+}
+
+impl<F: Scalable> Scalable for Pulse<F> {}
+
+/// A force multiplied by `sin(2π · frequency · time + phase)`, created with
+/// [`ForceExt::oscillating`](super::combinator::ForceExt::oscillating).
+///
+/// Useful to drive an oscillator, like a mass on a [`Spring`](super::spring::Spring), at its
+/// resonant frequency.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let driver = uniform(vec2(2.0, 0.0)).oscillating(0.5, 0.0);
+/// let mut obj = Object2dBuilder::new().build();
+///
+/// obj.apply_at(&driver, 1.5);
+/// assert!((obj.force.x + 2.0).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sinusoidal<F> {
+    pub force: F,
+    /// The frequency in hertz.
+    pub frequency: f32,
+    /// The phase in radians.
+    pub phase: f32,
+}
+
+impl<F> Sinusoidal<F> {
+    /// Returns the factor the force is multiplied by at `time`, between -1 and 1.
+    #[must_use]
+    pub fn factor(&self, time: f32) -> f32 {
+        sin(TAU * self.frequency * time + self.phase)
+    }
+}
+
+impl<F: TimedForce + Scalable> TimedForce for Sinusoidal<F> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        apply_scaled(obj, self.factor(time), |obj| {
+            self.force.apply_2d_at(obj, time);
+        });
+    }
+}
+
+impl<F: Scalable> Scalable for Sinusoidal<F> {}
+
+/// A force multiplied by an arbitrary function of the simulation time, created with
+/// [`ForceExt::modulated`](super::combinator::ForceExt::modulated).
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     forces::{combinator::ForceExt, field::uniform},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// let decay = uniform(vec2(8.0, 0.0)).modulated(|time| 1.0 / (1.0 + time));
+/// let mut obj = Object2dBuilder::new().build();
+///
+/// obj.apply_at(&decay, 3.0);
+/// assert_eq!(obj.force, vec2(2.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Modulated<F, M> {
+    pub force: F,
+    pub modulation: M,
+}
+
+impl<F: TimedForce + Scalable, M: Fn(f32) -> f32> TimedForce for Modulated<F, M> {
+    fn apply_2d_at(&self, obj: &mut Object2d, time: f32) {
+        apply_scaled(obj, (self.modulation)(time), |obj| {
+            self.force.apply_2d_at(obj, time);
+        });
+    }
+}
+
+impl<F: Scalable, M> Scalable for Modulated<F, M> {}
@@ -0,0 +1,54 @@
+use core::f32::consts::{FRAC_PI_2, PI};
+
+use super::sqrt::sqrt;
+
+/// Calculates the arc cosine of a given number, in radians.
+///
+/// Uses the polynomial approximation 4.4.46 of Abramowitz and Stegun, accurate to about `2e-8` radians.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::formulas::acos::acos;
+/// let result = acos(1.0);
+/// assert_eq!(result, 0.0);
+///
+/// let result = acos(0.5);
+/// assert!((result - std::f32::consts::FRAC_PI_3).abs() < 1e-6);
+///
+/// let result = acos(2.0);
+/// assert!(result.is_nan());
+/// ```
+///
+/// # Parameters
+///
+/// - `number`: The cosine of the angle. If it is outside of `[-1, 1]`, the function will return `NaN`.
+///
+/// # Returns
+///
+/// The angle in `[0, π]` whose cosine is the given number, or `NaN` if there is no such angle.
+#[must_use]
+pub fn acos(number: f32) -> f32 {
+    if !(-1.0..=1.0).contains(&number) {
+        return f32::NAN;
+    }
+    let x = number.abs();
+    let polynomial = [
+        -0.001_262_491_1,
+        0.006_670_09,
+        -0.017_088_126,
+        0.030_891_88,
+        -0.050_174_303,
+        0.088_978_99,
+        -0.214_598_8,
+        FRAC_PI_2,
+    ]
+    .iter()
+    .fold(0.0, |result, coefficient| result * x + coefficient);
+    let angle = sqrt(1.0 - x) * polynomial;
+    if number < 0.0 {
+        PI - angle
+    } else {
+        angle
+    }
+}
@@ -1,5 +1,7 @@
+pub mod acos;
 pub mod dot;
 pub mod elastic_collision;
 pub mod pitagoras;
 pub mod pow;
+pub mod sin;
 pub mod sqrt;
@@ -0,0 +1,79 @@
+use core::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// Calculates the sine of an angle given in radians.
+///
+/// The angle is reduced to `[-π/2, π/2]` and evaluated with its Taylor series up to the
+/// eleventh power, accurate to about `1e-7`.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::formulas::sin::sin;
+/// let result = sin(0.0);
+/// assert_eq!(result, 0.0);
+///
+/// let result = sin(std::f32::consts::FRAC_PI_2);
+/// assert!((result - 1.0).abs() < 1e-6);
+///
+/// let result = sin(-std::f32::consts::FRAC_PI_6);
+/// assert!((result + 0.5).abs() < 1e-6);
+/// ```
+///
+/// # Parameters
+///
+/// - `angle`: The angle in radians.
+///
+/// # Returns
+///
+/// The sine of the angle.
+#[must_use]
+pub fn sin(angle: f32) -> f32 {
+    let mut x = angle % TAU;
+    if x > PI {
+        x -= TAU;
+    } else if x < -PI {
+        x += TAU;
+    }
+    if x > FRAC_PI_2 {
+        x = PI - x;
+    } else if x < -FRAC_PI_2 {
+        x = -PI - x;
+    }
+    let x2 = x * x;
+    let series = [
+        -1.0 / 39_916_800.0,
+        1.0 / 362_880.0,
+        -1.0 / 5040.0,
+        1.0 / 120.0,
+        -1.0 / 6.0,
+        1.0,
+    ]
+    .iter()
+    .fold(0.0, |result, coefficient| result * x2 + coefficient);
+    x * series
+}
+
+/// Calculates the cosine of an angle given in radians.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::formulas::sin::cos;
+/// let result = cos(0.0);
+/// assert!((result - 1.0).abs() < 1e-6);
+///
+/// let result = cos(std::f32::consts::PI);
+/// assert!((result + 1.0).abs() < 1e-6);
+/// ```
+///
+/// # Parameters
+///
+/// - `angle`: The angle in radians.
+///
+/// # Returns
+///
+/// The cosine of the angle.
+#[must_use]
+pub fn cos(angle: f32) -> f32 {
+    sin(angle + FRAC_PI_2)
+}
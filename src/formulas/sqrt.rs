@@ -1,8 +1,12 @@
 /// Calculates the square root of a given number.
 ///
+/// The root is accurate to the precision of an `f32`, the initial guess from the bits of the
+/// number alone could be off by 6% and made collision normals and distances visibly wrong.
+///
 /// # Examples
 ///
 /// ```
+/// # use motion::formulas::sqrt::sqrt;
 /// let result = sqrt(4.0);
 /// assert_eq!(result, 2.0);
 ///
@@ -11,6 +15,15 @@
 ///
 /// let result = sqrt(-1.0);
 /// assert!(result.is_nan());
+///
+/// assert!((sqrt(2.0) - core::f32::consts::SQRT_2).abs() < 1e-6);
+/// assert_eq!(sqrt(0.0), 0.0);
+/// assert_eq!(sqrt(f32::INFINITY), f32::INFINITY);
+/// for i in 1..1000 {
+///     let number = i as f32 * 0.37;
+///     let root = sqrt(number);
+///     assert!((root * root - number).abs() <= number * 1e-6);
+/// }
 /// ```
 ///
 /// # Parameters
@@ -25,5 +38,13 @@ pub fn sqrt(number: f32) -> f32 {
     if number < 0.0 {
         return f32::NAN;
     }
-    f32::from_bits((number.to_bits() + 0x3f80_0000) >> 1)
+    if number == 0.0 || number.is_infinite() {
+        return number;
+    }
+    // Initial guess from halving the exponent, refined with Newton-Raphson iterations.
+    let mut root = f32::from_bits((number.to_bits() + 0x3f80_0000) >> 1);
+    for _ in 0..3 {
+        root = 0.5 * (root + number / root);
+    }
+    root
 }
@@ -0,0 +1,70 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::integrator::Integrator;
+
+/// Explicit (forward) Euler integration.
+///
+/// The position is advanced with the velocity from the start of the step, which makes
+/// the energy of oscillating systems grow over time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExplicitEuler;
+
+impl Integrator for ExplicitEuler {
+    /// Advances the object with `x += v·dt` and then `v += a·dt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     integrators::{euler::ExplicitEuler, integrator::Integrator},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new().velocity(vec2(1.0, 0.0)).build();
+    /// ExplicitEuler.step_2d(&mut obj, 1.0, |_, _, _| vec2(0.0, 2.0));
+    ///
+    /// assert_eq!(obj.vec, vec2(1.0, 0.0));
+    /// assert_eq!(obj.velocity, vec2(1.0, 2.0));
+    /// ```
+    fn step_2d<A>(&self, obj: &mut Object2d, delta_time: f32, acceleration: A)
+    where
+        A: Fn(f32, Vec2d, Vec2d) -> Vec2d,
+    {
+        let a = acceleration(0.0, obj.vec, obj.velocity);
+        obj.vec += obj.velocity * delta_time;
+        obj.velocity += a * delta_time;
+    }
+}
+
+/// Semi-implicit (symplectic) Euler integration.
+///
+/// The velocity is advanced first and the new velocity moves the position, which keeps
+/// the energy of oscillating systems bounded at the cost of a single acceleration sample.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SemiImplicitEuler;
+
+impl Integrator for SemiImplicitEuler {
+    /// Advances the object with `v += a·dt` and then `x += v·dt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     integrators::{euler::SemiImplicitEuler, integrator::Integrator},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new().velocity(vec2(1.0, 0.0)).build();
+    /// SemiImplicitEuler.step_2d(&mut obj, 1.0, |_, _, _| vec2(0.0, 2.0));
+    ///
+    /// assert_eq!(obj.vec, vec2(1.0, 2.0));
+    /// assert_eq!(obj.velocity, vec2(1.0, 2.0));
+    /// ```
+    fn step_2d<A>(&self, obj: &mut Object2d, delta_time: f32, acceleration: A)
+    where
+        A: Fn(f32, Vec2d, Vec2d) -> Vec2d,
+    {
+        obj.velocity += acceleration(0.0, obj.vec, obj.velocity) * delta_time;
+        obj.vec += obj.velocity * delta_time;
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::{
+    euler::{ExplicitEuler, SemiImplicitEuler},
+    rk4::Rk4,
+    verlet::VelocityVerlet,
+};
+
+/// A trait for numerical integrators that advance the linear motion of 2D objects.
+///
+/// The acceleration is given as a function of the time elapsed since the start of the step and
+/// of the position and velocity of the object, so higher order integrators can sample it several
+/// times inside a single step.
+///
+/// # Examples
+///
+/// Comparing the energy drift of the integrators on a spring with `k = m = 1`:
+///
+/// ```
+/// # use motion::{
+/// #     integrators::integrator::{Integrator, IntegratorKind},
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// # };
+/// fn drift(integrator: IntegratorKind) -> f32 {
+///     let mut obj = Object2dBuilder::new().mass(1.0).position(vec2(1.0, 0.0)).build();
+///     for _ in 0..1000 {
+///         integrator.step_2d(&mut obj, 0.01, |_elapsed, position, _velocity| -position);
+///     }
+///     let energy = 0.5 * (obj.velocity * obj.velocity) + 0.5 * (obj.vec * obj.vec);
+///     (energy - 0.5).abs()
+/// }
+///
+/// let explicit = drift(IntegratorKind::ExplicitEuler);
+/// let semi_implicit = drift(IntegratorKind::SemiImplicitEuler);
+/// let verlet = drift(IntegratorKind::VelocityVerlet);
+/// let rk4 = drift(IntegratorKind::Rk4);
+///
+/// assert!(explicit > 0.02);
+/// assert!(semi_implicit < 0.01);
+/// assert!(verlet < 0.001);
+/// assert!(rk4 < 0.001);
+/// ```
+pub trait Integrator {
+    /// Advances the position and velocity of `obj` by `delta_time` seconds.
+    ///
+    /// # Parameters
+    ///
+    /// - `obj`: The object to advance.
+    /// - `delta_time`: The time step in seconds.
+    /// - `acceleration`: A function returning the acceleration for the time elapsed since the start
+    ///   of the step and a given position and velocity.
+    fn step_2d<A>(&self, obj: &mut Object2d, delta_time: f32, acceleration: A)
+    where
+        A: Fn(f32, Vec2d, Vec2d) -> Vec2d;
+}
+
+/// The integrators provided by motion, used to select one per world or per object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegratorKind {
+    /// First order, fastest and the least stable, see [`ExplicitEuler`].
+    ExplicitEuler,
+    /// First order and symplectic, see [`SemiImplicitEuler`].
+    #[default]
+    SemiImplicitEuler,
+    /// Second order and symplectic, see [`VelocityVerlet`].
+    VelocityVerlet,
+    /// Fourth order Runge-Kutta, the most accurate and the most expensive, see [`Rk4`].
+    Rk4,
+}
+
+impl Integrator for IntegratorKind {
+    fn step_2d<A>(&self, obj: &mut Object2d, delta_time: f32, acceleration: A)
+    where
+        A: Fn(f32, Vec2d, Vec2d) -> Vec2d,
+    {
+        match self {
+            IntegratorKind::ExplicitEuler => ExplicitEuler.step_2d(obj, delta_time, acceleration),
+            IntegratorKind::SemiImplicitEuler => {
+                SemiImplicitEuler.step_2d(obj, delta_time, acceleration);
+            }
+            IntegratorKind::VelocityVerlet => {
+                VelocityVerlet.step_2d(obj, delta_time, acceleration);
+            }
+            IntegratorKind::Rk4 => Rk4.step_2d(obj, delta_time, acceleration),
+        }
+    }
+}
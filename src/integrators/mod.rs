@@ -0,0 +1,4 @@
+pub mod euler;
+pub mod integrator;
+pub mod rk4;
+pub mod verlet;
@@ -0,0 +1,49 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::integrator::Integrator;
+
+/// Classic fourth order Runge-Kutta integration.
+///
+/// Samples the acceleration four times per step, it is the most accurate of the provided
+/// integrators and also the most expensive.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rk4;
+
+impl Integrator for Rk4 {
+    /// Advances the object with a weighted average of four samples of its velocity and acceleration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     integrators::{integrator::Integrator, rk4::Rk4},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new().velocity(vec2(1.0, 0.0)).build();
+    /// Rk4.step_2d(&mut obj, 1.0, |_, _, _| vec2(0.0, 2.0));
+    ///
+    /// assert_eq!(obj.vec, vec2(1.0, 1.0));
+    /// assert_eq!(obj.velocity, vec2(1.0, 2.0));
+    /// ```
+    fn step_2d<A>(&self, obj: &mut Object2d, delta_time: f32, acceleration: A)
+    where
+        A: Fn(f32, Vec2d, Vec2d) -> Vec2d,
+    {
+        let half = 0.5 * delta_time;
+        let (x, v) = (obj.vec, obj.velocity);
+
+        let k1_x = v;
+        let k1_v = acceleration(0.0, x, v);
+        let k2_x = v + k1_v * half;
+        let k2_v = acceleration(half, x + k1_x * half, k2_x);
+        let k3_x = v + k2_v * half;
+        let k3_v = acceleration(half, x + k2_x * half, k3_x);
+        let k4_x = v + k3_v * delta_time;
+        let k4_v = acceleration(delta_time, x + k3_x * delta_time, k4_x);
+
+        let sixth = delta_time / 6.0;
+        obj.vec = x + (k1_x + k2_x * 2.0 + k3_x * 2.0 + k4_x) * sixth;
+        obj.velocity = v + (k1_v + k2_v * 2.0 + k3_v * 2.0 + k4_v) * sixth;
+    }
+}
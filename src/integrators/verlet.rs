@@ -0,0 +1,39 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+use super::integrator::Integrator;
+
+/// Velocity Verlet integration.
+///
+/// Second order accurate and symplectic, it samples the acceleration at the start and at
+/// the end of the step and averages both to advance the velocity.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VelocityVerlet;
+
+impl Integrator for VelocityVerlet {
+    /// Advances the object with `x += v·dt + ½·a·dt²` and `v += ½·(a + a')·dt`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     integrators::{integrator::Integrator, verlet::VelocityVerlet},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new().velocity(vec2(1.0, 0.0)).build();
+    /// VelocityVerlet.step_2d(&mut obj, 1.0, |_, _, _| vec2(0.0, 2.0));
+    ///
+    /// assert_eq!(obj.vec, vec2(1.0, 1.0));
+    /// assert_eq!(obj.velocity, vec2(1.0, 2.0));
+    /// ```
+    fn step_2d<A>(&self, obj: &mut Object2d, delta_time: f32, acceleration: A)
+    where
+        A: Fn(f32, Vec2d, Vec2d) -> Vec2d,
+    {
+        let a = acceleration(0.0, obj.vec, obj.velocity);
+        obj.vec += obj.velocity * delta_time + a * (0.5 * delta_time * delta_time);
+        let predicted = obj.velocity + a * delta_time;
+        let next = acceleration(delta_time, obj.vec, predicted);
+        obj.velocity += (a + next) * (0.5 * delta_time);
+    }
+}
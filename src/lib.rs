@@ -29,6 +29,9 @@
 //! More examples in <https://github.com/Juanperias/motion/tree/main/examples>
 //!
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(feature = "vec")]
 pub mod vec;
 
@@ -46,3 +49,12 @@ pub mod event_loop;
 
 #[cfg(feature = "collision")]
 pub mod collision;
+
+#[cfg(feature = "constraint")]
+pub mod constraint;
+
+#[cfg(feature = "integrators")]
+pub mod integrators;
+
+#[cfg(feature = "world")]
+pub mod world;
@@ -0,0 +1,41 @@
+/// Describes how an object takes part in the simulation.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::obj::body_type::BodyType;
+/// let floor = BodyType::Static;
+/// assert!(floor.is_static());
+/// assert!(!floor.is_dynamic());
+/// assert_eq!(BodyType::default(), BodyType::Dynamic);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BodyType {
+    /// The object never moves and behaves as if it had infinite mass.
+    Static,
+    /// The object moves only by its own velocity, it ignores forces and is never pushed by collisions.
+    Kinematic,
+    /// The object is fully simulated, forces and collisions change its motion.
+    #[default]
+    Dynamic,
+}
+
+impl BodyType {
+    /// Returns `true` if the body type is `BodyType::Static`.
+    #[must_use]
+    pub fn is_static(&self) -> bool {
+        *self == BodyType::Static
+    }
+
+    /// Returns `true` if the body type is `BodyType::Kinematic`.
+    #[must_use]
+    pub fn is_kinematic(&self) -> bool {
+        *self == BodyType::Kinematic
+    }
+
+    /// Returns `true` if the body type is `BodyType::Dynamic`.
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        *self == BodyType::Dynamic
+    }
+}
@@ -0,0 +1,59 @@
+use crate::formulas::sqrt::sqrt;
+
+/// Describes how the surface of an object behaves when it touches other objects.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::obj::material::Material;
+/// let rubber = Material {
+///     restitution: 0.8,
+///     static_friction: 1.0,
+///     kinetic_friction: 0.8,
+/// };
+/// let wood = Material {
+///     restitution: 0.3,
+///     static_friction: 0.25,
+///     kinetic_friction: 0.2,
+/// };
+///
+/// let contact = rubber.combine(&wood);
+/// assert_eq!(contact.restitution, 0.3);
+/// assert_eq!(contact.static_friction, 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Material {
+    /// The fraction of the normal speed kept after a bounce, `1.0` is perfectly elastic.
+    pub restitution: f32,
+    /// The Coulomb friction coefficient that has to be overcome for the surfaces to start sliding.
+    pub static_friction: f32,
+    /// The Coulomb friction coefficient while the surfaces slide against each other.
+    pub kinetic_friction: f32,
+}
+
+impl Material {
+    /// Combines the materials of two touching objects into the material of their contact.
+    ///
+    /// The restitution is the smallest of both, so a soft object stops any bounce, and the friction
+    /// coefficients are the geometric mean of both, so a frictionless object never sticks.
+    #[must_use]
+    pub fn combine(&self, other: &Material) -> Material {
+        Material {
+            restitution: self.restitution.min(other.restitution),
+            static_friction: sqrt(self.static_friction * other.static_friction),
+            kinetic_friction: sqrt(self.kinetic_friction * other.kinetic_friction),
+        }
+    }
+}
+
+impl Default for Material {
+    /// A material like wood, which bounces a little and grips enough for resting objects to stop
+    /// sliding.
+    fn default() -> Self {
+        Material {
+            restitution: 0.2,
+            static_friction: 0.6,
+            kinetic_friction: 0.4,
+        }
+    }
+}
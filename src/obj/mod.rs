@@ -1 +1,3 @@
+pub mod body_type;
+pub mod material;
 pub mod obj_2d;
@@ -1,11 +1,19 @@
+use core::f32::consts::PI;
+
 use crate::{
-    collision::shape::Shape,
-    forces::force::Force,
+    collision::{
+        polygon::{self, Convex},
+        shape::{ChildShape, Shape},
+        support::{Placed, Support},
+    },
+    forces::force::{Force, TimedForce},
+    integrators::integrator::{Integrator, IntegratorKind},
+    obj::{body_type::BodyType, material::Material},
     vec::vec_2d::{vec2, Vec2d},
 };
 
 /// Represents a 2D object with physical properties and shape.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Object2d {
     /// The position vector of the object.
     pub vec: Vec2d,
@@ -21,6 +29,34 @@ pub struct Object2d {
     pub radius: f32,
     /// The shape of the object.
     pub shape: Shape,
+    /// How the object takes part in the simulation.
+    pub body_type: BodyType,
+    /// The rotation of the object in radians.
+    pub angle: f32,
+    /// The angular velocity of the object in radians per second.
+    pub angular_velocity: f32,
+    /// The sum of the forces added since the last integration step.
+    pub force: Vec2d,
+    /// The sum of the torques added since the last integration step.
+    pub torque: f32,
+    /// The sum of the impulses added since the last integration step.
+    pub impulse: Vec2d,
+    /// The sum of the accelerations added since the last integration step, like gravity, which
+    /// act the same on every object whatever its mass.
+    pub added_acceleration: Vec2d,
+    /// The integrator used for this object, `None` to use the one of the world it belongs to.
+    pub integrator: Option<IntegratorKind>,
+    /// The factor applied to the gravity acting on the object, `0.0` disables gravity for it.
+    pub gravity_scale: f32,
+    /// How the surface of the object behaves when it touches other objects.
+    pub material: Material,
+    /// The electric charge of the object in coulombs.
+    pub charge: f32,
+    /// Whether the object sleeps, a sleeping object is not integrated nor tested for collisions
+    /// with other resting objects until something wakes it.
+    pub sleeping: bool,
+    /// How long the object has been moving slowly enough to fall asleep, in seconds.
+    pub sleep_time: f32,
 }
 
 impl Object2d {
@@ -38,7 +74,7 @@ impl Object2d {
     ///
     /// # Returns
     ///
-    /// A new `Object2d` instance.
+    /// A new dynamic `Object2d` instance.
     ///
     /// # Examples
     ///
@@ -63,7 +99,630 @@ impl Object2d {
             acceleration,
             radius,
             shape,
+            body_type: BodyType::Dynamic,
+            angle: 0.0,
+            angular_velocity: 0.0,
+            force: Vec2d::ZERO,
+            torque: 0.0,
+            impulse: Vec2d::ZERO,
+            added_acceleration: Vec2d::ZERO,
+            integrator: None,
+            gravity_scale: 1.0,
+            material: Material::default(),
+            charge: 0.0,
+            sleeping: false,
+            sleep_time: 0.0,
+        }
+    }
+
+    /// Returns whether the object is dynamic and not sleeping, so it moves on its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::obj::{body_type::BodyType, obj_2d::Object2dBuilder};
+    /// assert!(Object2dBuilder::new().build().is_awake());
+    /// assert!(!Object2dBuilder::new().sleeping(true).build().is_awake());
+    /// assert!(!Object2dBuilder::new().body_type(BodyType::Static).build().is_awake());
+    /// ```
+    #[must_use]
+    pub fn is_awake(&self) -> bool {
+        self.body_type.is_dynamic() && !self.sleeping
+    }
+
+    /// Wakes the object up and restarts the time it needs to fall asleep again.
+    pub fn wake(&mut self) {
+        self.sleeping = false;
+        self.sleep_time = 0.0;
+    }
+
+    /// Puts the object to sleep, stopping it.
+    pub fn sleep(&mut self) {
+        self.sleeping = true;
+        self.velocity = Vec2d::ZERO;
+        self.angular_velocity = 0.0;
+    }
+
+    /// Returns the inverse of the mass of the object.
+    ///
+    /// Static and kinematic objects, as well as objects without mass, behave as if their mass
+    /// were infinite, so their inverse mass is `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::obj::{body_type::BodyType, obj_2d::Object2dBuilder};
+    /// let ball = Object2dBuilder::new().mass(2.0).build();
+    /// assert_eq!(ball.inverse_mass(), 0.5);
+    ///
+    /// let floor = Object2dBuilder::new().mass(2.0).body_type(BodyType::Static).build();
+    /// assert_eq!(floor.inverse_mass(), 0.0);
+    /// ```
+    #[must_use]
+    pub fn inverse_mass(&self) -> f32 {
+        if self.body_type.is_dynamic() && self.mass > 0.0 {
+            1.0 / self.mass
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the moment of inertia of the object around its position, derived from its shape and mass.
+    ///
+    /// Circles use `½·m·r²`, AABBs and rectangles use `m·(w² + h²) / 12`, polygons are taken as uniformly
+    /// dense around the origin of their vertices, custom shapes scale
+    /// [`Support::unit_inertia`] by the mass, objects without a shape, half-planes and
+    /// heightfields have no inertia. Compounds add up the inertia of their children around the
+    /// position of the object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder};
+    /// let wheel = Object2dBuilder::new().mass(2.0).radius(3.0).shape(Shape::Circle).build();
+    /// assert_eq!(wheel.inertia(), 9.0);
+    /// ```
+    #[must_use]
+    pub fn inertia(&self) -> f32 {
+        match self.shape {
+            Shape::None | Shape::HalfPlane(_) | Shape::Heightfield(..) => 0.0,
+            Shape::Circle => 0.5 * self.mass * self.radius * self.radius,
+            Shape::AABB(min, max) | Shape::Rect(min, max) => {
+                let size = min.component(max);
+                self.mass * (size.x * size.x + size.y * size.y) / 12.0
+            }
+            Shape::Polygon(vertices) => self.mass * polygon::unit_inertia(vertices),
+            Shape::Custom(shape) => self.mass * shape.unit_inertia(),
+            Shape::Compound(_) => self
+                .children()
+                .map(|child| {
+                    let offset = self.vec.component(child.vec);
+                    child.inertia() + child.mass * (offset * offset)
+                })
+                .sum(),
+        }
+    }
+
+    /// Returns the area of the shape of the object, half-planes and heightfields are infinite,
+    /// custom shapes give [`Support::area`] and compounds add up the area of their children.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let crate_ = Object2dBuilder::new()
+    ///     .shape(Shape::AABB(vec2(0.0, 0.0), vec2(4.0, 2.0)))
+    ///     .build();
+    /// assert_eq!(crate_.area(), 8.0);
+    /// ```
+    #[must_use]
+    pub fn area(&self) -> f32 {
+        match &self.shape {
+            Shape::None => 0.0,
+            Shape::Circle => PI * self.radius * self.radius,
+            Shape::HalfPlane(_) | Shape::Heightfield(..) => f32::INFINITY,
+            Shape::AABB(min, max) | Shape::Rect(min, max) => {
+                let size = min.component(*max);
+                (size.x * size.y).abs()
+            }
+            Shape::Polygon(vertices) => polygon::area(vertices),
+            Shape::Custom(shape) => shape.area(),
+            Shape::Compound(children) => children.iter().map(|child| self.child_area(child)).sum(),
+        }
+    }
+
+    /// Returns the width of the object seen from `direction`, used as its cross-sectional area in 2D.
+    ///
+    /// Circles always measure their diameter, AABBs measure the projection of their sides on the
+    /// line perpendicular to `direction`, rectangles, polygons and custom shapes their span on
+    /// that line, half-planes and heightfields are infinitely wide and objects without a shape
+    /// have no cross section. Compounds measure the span covered by their children.
+    /// A zero `direction` measures AABBs from the `x` axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let crate_ = Object2dBuilder::new()
+    ///     .shape(Shape::AABB(vec2(0.0, 0.0), vec2(4.0, 2.0)))
+    ///     .build();
+    /// assert_eq!(crate_.cross_section(vec2(1.0, 0.0)), 2.0);
+    /// assert_eq!(crate_.cross_section(vec2(0.0, -3.0)), 4.0);
+    /// ```
+    #[must_use]
+    pub fn cross_section(&self, direction: Vec2d) -> f32 {
+        match self.shape {
+            Shape::None => 0.0,
+            Shape::Circle => 2.0 * self.radius,
+            Shape::HalfPlane(_) | Shape::Heightfield(..) => f32::INFINITY,
+            Shape::AABB(min, max) => {
+                let size = min.component(max);
+                let length = direction.magnitude();
+                if length == 0.0 {
+                    return size.y.abs();
+                }
+                (size.x * direction.y).abs() / length + (size.y * direction.x).abs() / length
+            }
+            Shape::Rect(..) | Shape::Polygon(_) => Convex::of(self).map_or(0.0, |convex| {
+                let across = across(direction);
+                let (mut low, mut high) = (f32::INFINITY, f32::NEG_INFINITY);
+                for vertex in convex.vertices() {
+                    low = low.min(vertex * across);
+                    high = high.max(vertex * across);
+                }
+                (high - low).max(0.0)
+            }),
+            Shape::Custom(shape) => {
+                let across = across(direction);
+                let turned = Placed::Custom {
+                    shape,
+                    position: Vec2d::ZERO,
+                    angle: self.angle,
+                };
+                turned.support(-across).component(turned.support(across)) * across
+            }
+            Shape::Compound(_) => {
+                let across = across(direction);
+                let (mut low, mut high) = (f32::INFINITY, f32::NEG_INFINITY);
+                for child in self.children() {
+                    let center = self.vec.component(child.vec) * across;
+                    let half = child.cross_section(direction) / 2.0;
+                    low = low.min(center - half);
+                    high = high.max(center + half);
+                }
+                (high - low).max(0.0)
+            }
+        }
+    }
+
+    /// Returns a child of a compound object as an object of its own, placed at the position of
+    /// the child with its share of the mass, or `None` if there is no such child. AABB children
+    /// come as a [`Shape::Rect`], which turns with the compound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::shape::{ChildShape, Shape, Shared},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::{vec2, Vec2d},
+    /// # };
+    /// static L_PIECE: [ChildShape; 2] = [
+    ///     ChildShape::new(Vec2d::new(0.0, 0.0), Shape::AABB(Vec2d::new(-0.5, -1.5), Vec2d::new(0.5, 1.5))),
+    ///     ChildShape::new(Vec2d::new(1.0, 1.0), Shape::AABB(Vec2d::new(-0.5, -0.5), Vec2d::new(0.5, 0.5))),
+    /// ];
+    /// let piece = Object2dBuilder::new()
+    ///     .mass(4.0)
+    ///     .position(vec2(2.0, 2.0))
+    ///     .shape(Shape::Compound(Shared::from_static(&L_PIECE)))
+    ///     .build();
+    ///
+    /// let foot = piece.child(1).unwrap();
+    /// assert_eq!(foot.vec, vec2(3.0, 3.0));
+    /// assert_eq!(foot.mass, 1.0);
+    /// assert_eq!(foot.shape, Shape::Rect(Vec2d::new(-0.5, -0.5), Vec2d::new(0.5, 0.5)));
+    /// assert!(piece.child(2).is_none());
+    /// ```
+    // Compounds have far fewer children than an `f32` counts exactly.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn child(&self, index: usize) -> Option<Object2d> {
+        let Shape::Compound(children) = &self.shape else {
+            return None;
+        };
+        let child = children.get(index)?;
+        let area: f32 = children.iter().map(|child| self.child_area(child)).sum();
+        let share = if area > 0.0 {
+            self.child_area(child) / area
+        } else {
+            1.0 / children.len() as f32
+        };
+        Some(Object2d {
+            vec: self.vec + child.offset.rotated(self.angle),
+            mass: self.mass * share,
+            shape: match &child.shape {
+                Shape::AABB(min, max) => Shape::Rect(*min, *max),
+                other => other.clone(),
+            },
+            radius: child.radius,
+            ..*self
+        })
+    }
+
+    /// Iterates over the children of a compound object, see [`Object2d::child`].
+    pub fn children(&self) -> impl Iterator<Item = Object2d> + '_ {
+        let count = match &self.shape {
+            Shape::Compound(children) => children.len(),
+            _ => 0,
+        };
+        (0..count).filter_map(|index| self.child(index))
+    }
+
+    /// Returns the smallest axis-aligned box containing the shape of the object, as its minimum
+    /// and maximum corners in world coordinates, or `None` for half-planes and heightfields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let ball = Object2dBuilder::new().position(vec2(1.0, 1.0)).radius(2.0).shape(Shape::Circle).build();
+    /// assert_eq!(ball.bounding_box(), Some((vec2(-1.0, -1.0), vec2(3.0, 3.0))));
+    /// ```
+    #[must_use]
+    pub fn bounding_box(&self) -> Option<(Vec2d, Vec2d)> {
+        match self.shape {
+            Shape::None => Some((self.vec, self.vec)),
+            Shape::Circle => {
+                let radius = Vec2d::new(self.radius, self.radius);
+                Some((self.vec - radius, self.vec + radius))
+            }
+            Shape::AABB(min, max) => Some((self.vec + min, self.vec + max)),
+            Shape::HalfPlane(_) | Shape::Heightfield(..) => None,
+            Shape::Rect(..) | Shape::Polygon(_) => Convex::of(self)?
+                .vertices()
+                .map(|vertex| (vertex, vertex))
+                .reduce(union),
+            Shape::Custom(_) => {
+                let placed = Placed::of(self)?;
+                let (x, y) = (Vec2d::new(1.0, 0.0), Vec2d::new(0.0, 1.0));
+                Some((
+                    Vec2d::new(placed.support(-x).x, placed.support(-y).y),
+                    Vec2d::new(placed.support(x).x, placed.support(y).y),
+                ))
+            }
+            Shape::Compound(_) => self
+                .children()
+                .filter_map(|child| child.bounding_box())
+                .reduce(union),
+        }
+    }
+
+    /// Returns the area of a child of the shape of the object.
+    fn child_area(&self, child: &ChildShape) -> f32 {
+        Object2d {
+            shape: child.shape.clone(),
+            radius: child.radius,
+            ..*self
+        }
+        .area()
+    }
+
+    /// Returns the inverse of the moment of inertia of the object.
+    ///
+    /// Objects that can not be rotated by forces return `0.0`, these are static and kinematic
+    /// objects, objects without inertia and AABBs, which are always axis aligned.
+    #[must_use]
+    pub fn inverse_inertia(&self) -> f32 {
+        let inertia = self.inertia();
+        if self.body_type.is_dynamic() && inertia > 0.0 && !self.shape.is_axis_aligned() {
+            1.0 / inertia
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the inverse of the mass the object opposes to an impulse along the unit vector
+    /// `direction` acting on the point at offset `r` from its position, which includes the part
+    /// of the impulse that goes into rotating the object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let obj = Object2dBuilder::new().mass(2.0).radius(1.0).shape(Shape::Circle).build();
+    /// assert_eq!(obj.inverse_mass_at(vec2(0.0, 0.0), vec2(1.0, 0.0)), 0.5);
+    /// assert_eq!(obj.inverse_mass_at(vec2(0.0, 1.0), vec2(1.0, 0.0)), 1.5);
+    /// ```
+    #[must_use]
+    pub fn inverse_mass_at(&self, r: Vec2d, direction: Vec2d) -> f32 {
+        let arm = r.cross(direction);
+        self.inverse_mass() + arm * arm * self.inverse_inertia()
+    }
+
+    /// Adds a force acting on the position of the object.
+    ///
+    /// The force is accumulated until the next call to [`Object2d::integrate`], so the order in which
+    /// forces are added does not change the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut obj = Object2dBuilder::new().mass(2.0).build();
+    /// obj.add_force(vec2(4.0, 0.0));
+    /// obj.add_force(vec2(0.0, 2.0));
+    /// obj.integrate(1.0);
+    ///
+    /// assert_eq!(obj.velocity, vec2(2.0, 1.0));
+    /// assert_eq!(obj.force, vec2(0.0, 0.0));
+    /// ```
+    pub fn add_force(&mut self, force: Vec2d) {
+        self.force += force;
+    }
+
+    /// Adds an acceleration to the object, which unlike a force does not depend on its mass, so
+    /// objects without mass are accelerated as well.
+    ///
+    /// The acceleration is accumulated until the next call to [`Object2d::integrate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut obj = Object2dBuilder::new().build();
+    /// obj.add_acceleration(vec2(0.0, 2.0));
+    /// obj.integrate(1.0);
+    ///
+    /// assert_eq!(obj.velocity, vec2(0.0, 2.0));
+    /// assert_eq!(obj.added_acceleration, vec2(0.0, 0.0));
+    /// ```
+    pub fn add_acceleration(&mut self, acceleration: Vec2d) {
+        self.added_acceleration += acceleration;
+    }
+
+    /// Adds an instantaneous change of momentum to the object.
+    ///
+    /// The impulse is accumulated until the next call to [`Object2d::integrate`], where it changes
+    /// the velocity of the object independently of the time step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut obj = Object2dBuilder::new().mass(2.0).build();
+    /// obj.add_impulse(vec2(4.0, 0.0));
+    /// obj.integrate(0.5);
+    ///
+    /// assert_eq!(obj.velocity, vec2(2.0, 0.0));
+    /// assert_eq!(obj.vec, vec2(1.0, 0.0));
+    /// ```
+    pub fn add_impulse(&mut self, impulse: Vec2d) {
+        self.impulse += impulse;
+    }
+
+    /// Adds a force acting on a point of the object, given in world coordinates.
+    ///
+    /// Besides the force itself, a torque is accumulated when the point is not the position of the object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut obj = Object2dBuilder::new().mass(2.0).radius(1.0).shape(Shape::Circle).build();
+    /// obj.add_force_at_point(vec2(0.0, 1.0), vec2(1.0, 0.0));
+    ///
+    /// assert_eq!(obj.force, vec2(0.0, 1.0));
+    /// assert_eq!(obj.torque, 1.0);
+    /// ```
+    pub fn add_force_at_point(&mut self, force: Vec2d, point: Vec2d) {
+        self.force += force;
+        self.torque += self.vec.component(point).cross(force);
+    }
+
+    /// Returns the velocity of the point at offset `r` from the position of the object, taking
+    /// its rotation into account. Static objects are treated as resting.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let wheel = Object2dBuilder::new().velocity(vec2(1.0, 0.0)).angular_velocity(2.0).build();
+    /// assert_eq!(wheel.point_velocity(vec2(0.0, 1.0)), vec2(-1.0, 0.0));
+    /// ```
+    #[must_use]
+    pub fn point_velocity(&self, r: Vec2d) -> Vec2d {
+        if self.body_type.is_static() {
+            return Vec2d::ZERO;
         }
+        self.velocity + r.perp() * self.angular_velocity
+    }
+
+    /// Immediately changes the velocity and angular velocity of the object by an impulse acting
+    /// on the point at offset `r` from its position.
+    ///
+    /// Unlike [`Object2d::add_impulse`] the impulse is not accumulated, which is what collision and
+    /// constraint solvers need. Only dynamic objects are affected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{collision::shape::Shape, obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut obj = Object2dBuilder::new().mass(2.0).radius(1.0).shape(Shape::Circle).build();
+    /// obj.apply_impulse_at(vec2(0.0, 2.0), vec2(1.0, 0.0));
+    ///
+    /// assert_eq!(obj.velocity, vec2(0.0, 1.0));
+    /// assert_eq!(obj.angular_velocity, 2.0);
+    /// ```
+    pub fn apply_impulse_at(&mut self, impulse: Vec2d, r: Vec2d) {
+        self.velocity += impulse * self.inverse_mass();
+        self.angular_velocity += r.cross(impulse) * self.inverse_inertia();
+    }
+
+    /// Clears the forces, torques, impulses and accelerations accumulated since the last
+    /// integration step.
+    pub fn clear_accumulators(&mut self) {
+        self.force = Vec2d::ZERO;
+        self.torque = 0.0;
+        self.impulse = Vec2d::ZERO;
+        self.added_acceleration = Vec2d::ZERO;
+    }
+
+    /// Advances the object by `delta_time` seconds using the accumulated forces and impulses, then clears them.
+    ///
+    /// The integrator of the object is used, or [`IntegratorKind::SemiImplicitEuler`] if it has none.
+    /// See [`Object2d::integrate_with`] for how each body type is advanced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{obj::obj_2d::Object2dBuilder, vec::vec_2d::vec2};
+    /// let mut obj = Object2dBuilder::new().mass(1.0).velocity(vec2(1.0, 0.0)).build();
+    /// obj.add_force(vec2(0.0, 2.0));
+    /// obj.integrate(0.5);
+    ///
+    /// assert_eq!(obj.velocity, vec2(1.0, 1.0));
+    /// assert_eq!(obj.vec, vec2(0.5, 0.5));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `delta_time`: The time step in seconds.
+    pub fn integrate(&mut self, delta_time: f32) {
+        let integrator = self.integrator.unwrap_or_default();
+        self.integrate_with(&integrator, delta_time);
+    }
+
+    /// Advances the object by `delta_time` seconds with the given integrator, then clears the accumulators.
+    ///
+    /// Dynamic objects first receive their accumulated impulses, then their linear motion is integrated
+    /// under their acceleration plus the accumulated accelerations and forces, while rotation always uses semi-implicit Euler.
+    /// Kinematic objects move only by their velocity and static objects never move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     integrators::verlet::VelocityVerlet,
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let mut obj = Object2dBuilder::new().mass(2.0).build();
+    /// obj.add_force(vec2(0.0, 4.0));
+    /// obj.integrate_with(&VelocityVerlet, 1.0);
+    ///
+    /// assert_eq!(obj.velocity, vec2(0.0, 2.0));
+    /// assert_eq!(obj.vec, vec2(0.0, 1.0));
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `integrator`: The integrator used for the linear motion.
+    /// - `delta_time`: The time step in seconds.
+    pub fn integrate_with<I: Integrator>(&mut self, integrator: &I, delta_time: f32) {
+        self.advance(integrator, delta_time, |_, _, _| Vec2d::ZERO);
+    }
+
+    /// Advances the object like [`Object2d::integrate_with`], evaluating `force` again at every
+    /// time, position and velocity the integrator samples during the step.
+    ///
+    /// The accumulators only hold the forces as they were at the start of the step, so springs,
+    /// drag or fields applied with [`Object2d::apply`] stay constant over the step and every
+    /// integrator follows the same trajectory under them. Forces passed here are instead sampled
+    /// by the integrator, which is what makes higher order integrators more accurate. Only the
+    /// forces and accelerations that `force` accumulates are sampled, its impulses and direct
+    /// changes of position or velocity are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::field::field_fn,
+    /// #     integrators::integrator::IntegratorKind,
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let spring = field_fn(|position| -position);
+    /// let mut obj = Object2dBuilder::new().mass(1.0).position(vec2(1.0, 0.0)).build();
+    /// obj.integrate_under(&IntegratorKind::Rk4, &spring, 0.0, 0.5);
+    ///
+    /// // Exactly cos(0.5) ≈ 0.87758 and -sin(0.5) ≈ -0.47943.
+    /// assert!((obj.vec.x - 0.87758).abs() < 1e-3);
+    /// assert!((obj.velocity.x + 0.47943).abs() < 1e-3);
+    /// ```
+    ///
+    /// Forces that change over time are evaluated at the time of each sample:
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::{combinator::ForceExt, field::uniform},
+    /// #     integrators::integrator::IntegratorKind,
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let push = uniform(vec2(1.0, 0.0)).modulated(|time| time - 2.0);
+    /// let mut obj = Object2dBuilder::new().mass(1.0).build();
+    /// obj.integrate_under(&IntegratorKind::Rk4, &push, 2.0, 1.0);
+    ///
+    /// // The acceleration grows from 0 to 1 over the step.
+    /// assert!((obj.velocity.x - 0.5).abs() < 1e-6);
+    /// assert!((obj.vec.x - 1.0 / 6.0).abs() < 1e-6);
+    /// ```
+    ///
+    /// # Parameters
+    ///
+    /// - `integrator`: The integrator used for the linear motion.
+    /// - `force`: The force evaluated by the integrator.
+    /// - `time`: The simulation time at the start of the step, each sample evaluates the force at
+    ///   this time plus the time elapsed in the step, see [`TimedForce::apply_2d_at`].
+    /// - `delta_time`: The time step in seconds.
+    pub fn integrate_under<I: Integrator, F: TimedForce>(
+        &mut self,
+        integrator: &I,
+        force: &F,
+        time: f32,
+        delta_time: f32,
+    ) {
+        let start = self.clone();
+        let inverse_mass = self.inverse_mass();
+        self.advance(integrator, delta_time, |elapsed, position, velocity| {
+            let mut sample = Object2d {
+                vec: position,
+                velocity,
+                ..start.clone()
+            };
+            sample.clear_accumulators();
+            sample.apply_at(force, time + elapsed);
+            sample.added_acceleration + sample.force * inverse_mass
+        });
+    }
+
+    /// Integrates the object under its accumulators plus the acceleration given by `sampled` at
+    /// each time, position and velocity the integrator samples, then clears the accumulators.
+    fn advance<I: Integrator, A: Fn(f32, Vec2d, Vec2d) -> Vec2d>(
+        &mut self,
+        integrator: &I,
+        delta_time: f32,
+        sampled: A,
+    ) {
+        match self.body_type {
+            BodyType::Static => {}
+            BodyType::Kinematic => {
+                self.vec += self.velocity * delta_time;
+                self.angle += self.angular_velocity * delta_time;
+            }
+            BodyType::Dynamic => {
+                let inverse_mass = self.inverse_mass();
+                let acceleration =
+                    self.acceleration + self.added_acceleration + self.force * inverse_mass;
+                self.velocity += self.impulse * inverse_mass;
+                integrator.step_2d(self, delta_time, |elapsed, position, velocity| {
+                    acceleration + sampled(elapsed, position, velocity)
+                });
+                self.angular_velocity += self.torque * self.inverse_inertia() * delta_time;
+                self.angle += self.angular_velocity * delta_time;
+            }
+        }
+        self.clear_accumulators();
     }
 
     /// Applies a force to the object.
@@ -75,13 +734,66 @@ impl Object2d {
     /// # Examples
     ///
     /// ```
+    /// # use motion::{
+    /// #     collision::shape::Shape,
+    /// #     forces::gravity::{gravity, EARTH_GRAVITY},
+    /// #     obj::obj_2d::Object2d,
+    /// #     vec::vec_2d::vec2,
+    /// # };
     /// let mut obj = Object2d::new(vec2(1.0, 2.0), 1.0, 1.0, vec2(0.0, 0.0), vec2(0.0, 0.0), 1.0, Shape::Circle);
-    /// let gravity = Gravity::new(EARTH_GRAVITY, 1.0, 0.016);
-    /// obj.apply(gravity);
+    /// let gravity = gravity(EARTH_GRAVITY);
+    /// obj.apply(&gravity);
     /// ```
     pub fn apply<T: Force>(&mut self, force: &T) {
         force.apply_2d(self);
     }
+
+    /// Applies a force to the object as it is at `time` seconds of simulation.
+    ///
+    /// # Parameters
+    ///
+    /// - `force`: The force to apply.
+    /// - `time`: The simulation time in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::{combinator::ForceExt, field::uniform},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// # };
+    /// let push = uniform(vec2(4.0, 0.0)).active_between(1.0, 2.0);
+    /// let mut obj = Object2dBuilder::new().build();
+    ///
+    /// obj.apply_at(&push, 0.5);
+    /// assert_eq!(obj.force, vec2(0.0, 0.0));
+    ///
+    /// obj.apply_at(&push, 1.5);
+    /// assert_eq!(obj.force, vec2(4.0, 0.0));
+    /// ```
+    pub fn apply_at<T: TimedForce>(&mut self, force: &T, time: f32) {
+        force.apply_2d_at(self, time);
+    }
+}
+
+/// Returns the unit vector perpendicular to `direction`, along which cross sections are measured,
+/// or the `y` axis for a zero `direction`.
+fn across(direction: Vec2d) -> Vec2d {
+    let length = direction.magnitude();
+    if length == 0.0 {
+        Vec2d::new(0.0, 1.0)
+    } else {
+        direction.perp() / length
+    }
+}
+
+/// Returns the smallest axis-aligned box containing two boxes given by their corners.
+fn union((min1, max1): (Vec2d, Vec2d), (min2, max2): (Vec2d, Vec2d)) -> (Vec2d, Vec2d) {
+    (
+        Vec2d::new(min1.x.min(min2.x), min1.y.min(min2.y)),
+        Vec2d::new(max1.x.max(max2.x), max1.y.max(max2.y)),
+    )
 }
 
 /// Creates a new `Object2d` instance with the specified properties using a more convenient syntax.
@@ -153,6 +865,30 @@ pub struct Object2dBuilder {
 
     /// The shape of the object.
     shape: Shape,
+
+    /// How the object takes part in the simulation.
+    body_type: BodyType,
+
+    /// The rotation of the object in radians.
+    angle: f32,
+
+    /// The angular velocity of the object in radians per second.
+    angular_velocity: f32,
+
+    /// The integrator used for the object.
+    integrator: Option<IntegratorKind>,
+
+    /// The factor applied to the gravity acting on the object.
+    gravity_scale: f32,
+
+    /// The material of the surface of the object.
+    material: Material,
+
+    /// The electric charge of the object.
+    charge: f32,
+
+    /// Whether the object starts asleep.
+    sleeping: bool,
 }
 
 impl Object2dBuilder {
@@ -166,6 +902,14 @@ impl Object2dBuilder {
             acceleration: vec2(0.0, 0.0),
             radius: 0.0,
             shape: Shape::None,
+            body_type: BodyType::Dynamic,
+            angle: 0.0,
+            angular_velocity: 0.0,
+            integrator: None,
+            gravity_scale: 1.0,
+            material: Material::default(),
+            charge: 0.0,
+            sleeping: false,
         }
     }
     #[must_use]
@@ -209,6 +953,55 @@ impl Object2dBuilder {
         self.shape = shape;
         self
     }
+
+    #[must_use]
+    pub fn body_type(mut self, body_type: BodyType) -> Self {
+        self.body_type = body_type;
+        self
+    }
+
+    #[must_use]
+    pub fn angle(mut self, angle: f32) -> Self {
+        self.angle = angle;
+        self
+    }
+
+    #[must_use]
+    pub fn angular_velocity(mut self, angular_velocity: f32) -> Self {
+        self.angular_velocity = angular_velocity;
+        self
+    }
+
+    #[must_use]
+    pub fn integrator(mut self, integrator: IntegratorKind) -> Self {
+        self.integrator = Some(integrator);
+        self
+    }
+
+    #[must_use]
+    pub fn gravity_scale(mut self, gravity_scale: f32) -> Self {
+        self.gravity_scale = gravity_scale;
+        self
+    }
+
+    #[must_use]
+    pub fn material(mut self, material: Material) -> Self {
+        self.material = material;
+        self
+    }
+
+    #[must_use]
+    pub fn charge(mut self, charge: f32) -> Self {
+        self.charge = charge;
+        self
+    }
+
+    #[must_use]
+    pub fn sleeping(mut self, sleeping: bool) -> Self {
+        self.sleeping = sleeping;
+        self
+    }
+
     #[must_use]
     pub fn build(self) -> Object2d {
         Object2d {
@@ -219,6 +1012,19 @@ impl Object2dBuilder {
             acceleration: self.acceleration,
             radius: self.radius,
             shape: self.shape,
+            body_type: self.body_type,
+            angle: self.angle,
+            angular_velocity: self.angular_velocity,
+            force: Vec2d::ZERO,
+            torque: 0.0,
+            impulse: Vec2d::ZERO,
+            added_acceleration: Vec2d::ZERO,
+            integrator: self.integrator,
+            gravity_scale: self.gravity_scale,
+            material: self.material,
+            charge: self.charge,
+            sleeping: self.sleeping,
+            sleep_time: 0.0,
         }
     }
 }
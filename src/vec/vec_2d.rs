@@ -1,5 +1,8 @@
-use crate::formulas::dot::length;
-use core::ops::{Add, Div, Mul, Sub};
+use crate::formulas::{
+    dot::length,
+    sin::{cos, sin},
+};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 /// `Vec2d` is a simple 2D vector struct used for various vector operations.
 ///
@@ -13,7 +16,7 @@ use core::ops::{Add, Div, Mul, Sub};
 /// assert_eq!(v1.magnitude(), 5.0);
 /// assert_eq!(v1.distance(v2), 5.0);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Vec2d {
     /// The x component of the vector
     pub x: f32,
@@ -22,6 +25,9 @@ pub struct Vec2d {
 }
 
 impl Vec2d {
+    /// The zero vector.
+    pub const ZERO: Vec2d = Vec2d { x: 0.0, y: 0.0 };
+
     /// Creates a new `Vec2d` with the given x and y components.
     ///
     /// # Examples
@@ -32,7 +38,7 @@ impl Vec2d {
     /// assert_eq!(vec.y, 2.0);
     /// ```
     #[must_use]
-    pub fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: f32, y: f32) -> Self {
         Vec2d { x, y }
     }
 
@@ -82,6 +88,62 @@ impl Vec2d {
         let ab = self.component(target);
         length(ab)
     }
+
+    /// Computes the 2D cross product (the z component of the 3D cross product) of this vector and `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::vec::vec_2d::Vec2d;
+    /// let v1 = Vec2d::new(1.0, 0.0);
+    /// let v2 = Vec2d::new(0.0, 2.0);
+    /// assert_eq!(v1.cross(v2), 2.0);
+    /// assert_eq!(v2.cross(v1), -2.0);
+    /// ```
+    #[must_use]
+    pub fn cross(&self, rhs: Vec2d) -> f32 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Returns the vector rotated a quarter turn, from +x towards +y.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::vec::vec_2d::Vec2d;
+    /// let v = Vec2d::new(1.0, 2.0);
+    /// assert_eq!(v.perp(), Vec2d::new(-2.0, 1.0));
+    /// ```
+    #[must_use]
+    pub fn perp(&self) -> Vec2d {
+        Vec2d {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Returns the vector rotated by `angle` radians, from +x towards +y. A zero angle returns
+    /// the vector unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::vec::vec_2d::Vec2d;
+    /// let v = Vec2d::new(2.0, 0.0).rotated(std::f32::consts::FRAC_PI_2);
+    /// assert!(v.x.abs() < 1e-6);
+    /// assert!((v.y - 2.0).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn rotated(&self, angle: f32) -> Vec2d {
+        if angle == 0.0 {
+            return *self;
+        }
+        let (sin, cos) = (sin(angle), cos(angle));
+        Vec2d {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
 }
 
 /// Implements the addition of two 2D vectors.
@@ -174,6 +236,61 @@ impl Div<f32> for Vec2d {
     }
 }
 
+/// Implements scalar multiplication for 2D vectors.
+///
+/// This implementation allows using the `*` operator to multiply each component of a `Vec2d` vector by a scalar value of type `f32`.
+/// For example, `Vec2d { x: 1.0, y: 2.0 } * 2.0` results in `Vec2d { x: 2.0, y: 4.0 }`.
+impl Mul<f32> for Vec2d {
+    type Output = Vec2d;
+
+    /// Multiplies each component of a `Vec2d` vector by a scalar value.
+    ///
+    /// # Parameters
+    /// - `self`: The vector to be scaled.
+    /// - `rhs`: The scalar value to multiply each component by.
+    ///
+    /// # Returns
+    /// A new `Vec2d` vector where each component is the product of the corresponding component of the input vector and the scalar.
+    fn mul(self, rhs: f32) -> Self::Output {
+        Vec2d {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+/// Implements the negation of a 2D vector.
+///
+/// This implementation allows using the unary `-` operator to flip the direction of a `Vec2d` vector.
+/// For example, `-Vec2d { x: 1.0, y: -2.0 }` results in `Vec2d { x: -1.0, y: 2.0 }`.
+impl Neg for Vec2d {
+    type Output = Vec2d;
+
+    /// Negates each component of a `Vec2d` vector.
+    fn neg(self) -> Self::Output {
+        Vec2d {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// Implements in place addition of two 2D vectors, allowing the use of the `+=` operator.
+impl AddAssign for Vec2d {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+/// Implements in place subtraction of two 2D vectors, allowing the use of the `-=` operator.
+impl SubAssign for Vec2d {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
 /// A convenience function to create a new `Vec2d`.
 ///
 /// # Examples
@@ -0,0 +1,110 @@
+use crate::{obj::obj_2d::Object2d, vec::vec_2d::Vec2d};
+
+/// What happens to the objects of a [`World2d`](super::World2d) that reach its bounds, see
+/// [`World2d::set_bounds`](super::World2d::set_bounds).
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::shape::Shape,
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::{bounds::BoundsBehaviour, World2d},
+/// # };
+/// let mut world = World2d::new();
+/// world.set_bounds((vec2(0.0, 0.0), vec2(10.0, 10.0)), BoundsBehaviour::Wrap);
+/// let asteroid = world.add_body(
+///     Object2dBuilder::new()
+///         .mass(1.0)
+///         .position(vec2(9.5, 5.0))
+///         .velocity(vec2(60.0, 0.0))
+///         .radius(0.5)
+///         .shape(Shape::Circle)
+///         .build(),
+/// );
+///
+/// world.step(1.0 / 60.0);
+/// assert_eq!(world.body(asteroid).unwrap().vec, vec2(0.5, 5.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundsBehaviour {
+    /// Objects are kept inside and bounce back with the restitution of their material.
+    Bounce,
+    /// Objects are kept inside and lose the part of their velocity pointing outside.
+    Clamp,
+    /// Objects whose position leaves through a side come back in through the opposite one.
+    Wrap,
+    /// Objects entirely outside the bounds are removed from the world.
+    Despawn,
+}
+
+/// Applies `behaviour` to an object for the bounds from `min` to `max`.
+///
+/// # Returns
+///
+/// `false` if the object has to be removed from the world.
+pub(crate) fn confine(
+    obj: &mut Object2d,
+    (min, max): (Vec2d, Vec2d),
+    behaviour: BoundsBehaviour,
+) -> bool {
+    let Some((low, high)) = obj.bounding_box() else {
+        return true;
+    };
+    let (low, high) = (low - obj.vec, high - obj.vec);
+    let restitution = obj.material.restitution;
+    let axes = [
+        (
+            &mut obj.vec.x,
+            &mut obj.velocity.x,
+            low.x,
+            high.x,
+            min.x,
+            max.x,
+        ),
+        (
+            &mut obj.vec.y,
+            &mut obj.velocity.y,
+            low.y,
+            high.y,
+            min.y,
+            max.y,
+        ),
+    ];
+    for (position, velocity, low, high, min, max) in axes {
+        match behaviour {
+            BoundsBehaviour::Bounce | BoundsBehaviour::Clamp => {
+                let outside = if *position + low < min {
+                    *position = min - low;
+                    *velocity < 0.0
+                } else if *position + high > max {
+                    *position = max - high;
+                    *velocity > 0.0
+                } else {
+                    false
+                };
+                if outside {
+                    *velocity = if behaviour == BoundsBehaviour::Bounce {
+                        -*velocity * restitution
+                    } else {
+                        0.0
+                    };
+                }
+            }
+            BoundsBehaviour::Wrap => {
+                if *position < min {
+                    *position += max - min;
+                } else if *position > max {
+                    *position -= max - min;
+                }
+            }
+            BoundsBehaviour::Despawn => {
+                if *position + high < min || *position + low > max {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
@@ -0,0 +1,31 @@
+use alloc::vec::Vec;
+
+/// The islands of a world: the sets of objects touching each other or connected by joints,
+/// kept as a disjoint set forest over the indices of the objects.
+pub(crate) struct Islands {
+    parent: Vec<usize>,
+}
+
+impl Islands {
+    /// Creates `len` islands of a single object each.
+    pub(crate) fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+        }
+    }
+
+    /// Returns the index of the object representing the island of object `index`.
+    pub(crate) fn find(&mut self, mut index: usize) -> usize {
+        while self.parent[index] != index {
+            self.parent[index] = self.parent[self.parent[index]];
+            index = self.parent[index];
+        }
+        index
+    }
+
+    /// Merges the islands of two objects.
+    pub(crate) fn union(&mut self, index1: usize, index2: usize) {
+        let (root1, root2) = (self.find(index1), self.find(index2));
+        self.parent[root1] = root2;
+    }
+}
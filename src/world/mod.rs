@@ -0,0 +1,813 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    collision::{
+        contact_constraint::ContactConstraint,
+        ray::{Ray2d, RayHit},
+        Collision2d,
+    },
+    constraint::{Constraint, Joint, DEFAULT_SOLVER_ITERATIONS},
+    forces::{explosion::Explosion, force::TimedForce, gravity::Gravity},
+    integrators::integrator::IntegratorKind,
+    obj::{
+        body_type::BodyType,
+        obj_2d::{Object2d, Object2dBuilder},
+    },
+    vec::vec_2d::Vec2d,
+};
+
+use bounds::BoundsBehaviour;
+use island::Islands;
+use sleep::Sleep;
+
+pub mod bounds;
+mod island;
+pub mod sleep;
+
+/// A handle to an object stored in a [`World2d`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BodyHandle(pub usize);
+
+/// A handle to a joint stored in a [`World2d`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JointHandle(pub usize);
+
+/// A joint of the world together with the objects it connects.
+#[derive(Debug, Clone, Copy)]
+struct JointSlot {
+    joint: Joint,
+    body1: BodyHandle,
+    body2: Option<BodyHandle>,
+}
+
+/// A collection of 2D objects that are advanced together.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     integrators::integrator::IntegratorKind,
+/// #     obj::obj_2d::Object2dBuilder,
+/// #     vec::vec_2d::vec2,
+/// #     world::World2dBuilder,
+/// # };
+/// let mut world = World2dBuilder::new()
+///     .gravity(vec2(0.0, 2.0))
+///     .integrator(IntegratorKind::VelocityVerlet)
+///     .build();
+/// let ball = world.add_body(Object2dBuilder::new().mass(1.0).velocity(vec2(2.0, 0.0)).build());
+/// let balloon = world.add_body(Object2dBuilder::new().mass(1.0).gravity_scale(-0.5).build());
+/// let speck = world.add_body(Object2dBuilder::new().build());
+///
+/// world.step(0.5);
+///
+/// assert_eq!(world.body(ball).unwrap().vec, vec2(1.0, 0.25));
+/// assert_eq!(world.body(balloon).unwrap().velocity, vec2(0.0, -0.5));
+/// // Gravity does not depend on the mass, objects without one fall as well.
+/// assert_eq!(world.body(speck).unwrap().velocity, vec2(0.0, 1.0));
+/// ```
+#[derive(Debug)]
+pub struct World2d {
+    /// The gravitational acceleration applied to every dynamic object, scaled by its `gravity_scale`.
+    pub gravity: Vec2d,
+    /// The integrator used for the objects that do not select their own.
+    pub integrator: IntegratorKind,
+    /// The number of velocity and position iterations used to solve the joints and contacts, see
+    /// [`Constraint`].
+    pub solver_iterations: usize,
+    /// When objects fall asleep, `None` keeps every object awake.
+    pub sleep: Option<Sleep>,
+    bodies: Vec<Option<Object2d>>,
+    joints: Vec<Option<JointSlot>>,
+    contacts: Vec<(BodyHandle, BodyHandle, ContactConstraint)>,
+    bounds: Option<((Vec2d, Vec2d), BoundsBehaviour)>,
+    time: f32,
+}
+
+impl Default for World2d {
+    fn default() -> Self {
+        World2dBuilder::new().build()
+    }
+}
+
+impl World2d {
+    /// Creates a new empty `World2d` with the default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an object to the world.
+    ///
+    /// # Returns
+    ///
+    /// The handle used to access the object later.
+    pub fn add_body(&mut self, obj: Object2d) -> BodyHandle {
+        if let Some(index) = self.bodies.iter().position(Option::is_none) {
+            self.bodies[index] = Some(obj);
+            BodyHandle(index)
+        } else {
+            self.bodies.push(Some(obj));
+            BodyHandle(self.bodies.len() - 1)
+        }
+    }
+
+    /// Removes an object from the world.
+    ///
+    /// The joints attached to the object are removed too. The handle of a removed object may be
+    /// reused by objects added later.
+    ///
+    /// # Returns
+    ///
+    /// The removed object, or `None` if the handle does not refer to an object of the world.
+    pub fn remove_body(&mut self, handle: BodyHandle) -> Option<Object2d> {
+        let obj = self.bodies.get_mut(handle.0).and_then(Option::take)?;
+        for slot in &mut self.joints {
+            if slot
+                .as_ref()
+                .is_some_and(|slot| slot.body1 == handle || slot.body2 == Some(handle))
+            {
+                *slot = None;
+            }
+        }
+        self.contacts
+            .retain(|(handle1, handle2, _)| *handle1 != handle && *handle2 != handle);
+        Some(obj)
+    }
+
+    /// Returns a reference to an object of the world.
+    #[must_use]
+    pub fn body(&self, handle: BodyHandle) -> Option<&Object2d> {
+        self.bodies.get(handle.0).and_then(Option::as_ref)
+    }
+
+    /// Returns a mutable reference to an object of the world.
+    #[must_use]
+    pub fn body_mut(&mut self, handle: BodyHandle) -> Option<&mut Object2d> {
+        self.bodies.get_mut(handle.0).and_then(Option::as_mut)
+    }
+
+    /// Iterates over the objects of the world together with their handles.
+    pub fn bodies(&self) -> impl Iterator<Item = (BodyHandle, &Object2d)> {
+        self.bodies
+            .iter()
+            .enumerate()
+            .filter_map(|(index, obj)| obj.as_ref().map(|obj| (BodyHandle(index), obj)))
+    }
+
+    /// Iterates mutably over the objects of the world together with their handles.
+    pub fn bodies_mut(&mut self) -> impl Iterator<Item = (BodyHandle, &mut Object2d)> {
+        self.bodies
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, obj)| obj.as_mut().map(|obj| (BodyHandle(index), obj)))
+    }
+
+    /// Adds a joint between two objects of the world.
+    ///
+    /// When `body2` is `None` the joint connects `body1` to the world itself, a static object at
+    /// the origin, so the second anchor of the joint is a point of the world.
+    ///
+    /// # Returns
+    ///
+    /// The handle used to access the joint later.
+    pub fn add_joint<J: Into<Joint>>(
+        &mut self,
+        body1: BodyHandle,
+        body2: Option<BodyHandle>,
+        joint: J,
+    ) -> JointHandle {
+        let slot = JointSlot {
+            joint: joint.into(),
+            body1,
+            body2,
+        };
+        if let Some(index) = self.joints.iter().position(Option::is_none) {
+            self.joints[index] = Some(slot);
+            JointHandle(index)
+        } else {
+            self.joints.push(Some(slot));
+            JointHandle(self.joints.len() - 1)
+        }
+    }
+
+    /// Removes a joint from the world.
+    ///
+    /// # Returns
+    ///
+    /// The removed joint, or `None` if the handle does not refer to a joint of the world.
+    pub fn remove_joint(&mut self, handle: JointHandle) -> Option<Joint> {
+        self.joints
+            .get_mut(handle.0)
+            .and_then(Option::take)
+            .map(|slot| slot.joint)
+    }
+
+    /// Returns a reference to a joint of the world.
+    #[must_use]
+    pub fn joint(&self, handle: JointHandle) -> Option<&Joint> {
+        self.joints
+            .get(handle.0)
+            .and_then(Option::as_ref)
+            .map(|slot| &slot.joint)
+    }
+
+    /// Returns a mutable reference to a joint of the world.
+    #[must_use]
+    pub fn joint_mut(&mut self, handle: JointHandle) -> Option<&mut Joint> {
+        self.joints
+            .get_mut(handle.0)
+            .and_then(Option::as_mut)
+            .map(|slot| &mut slot.joint)
+    }
+
+    /// Finds the first object hit by a ray.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::{ray::Ray2d, shape::Shape},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// #     world::World2d,
+    /// # };
+    /// let mut world = World2d::new();
+    /// let near = world.add_body(Object2dBuilder::new().position(vec2(3.0, 0.0)).radius(1.0).shape(Shape::Circle).build());
+    /// world.add_body(Object2dBuilder::new().position(vec2(6.0, 0.0)).radius(1.0).shape(Shape::Circle).build());
+    ///
+    /// let (handle, hit) = world.raycast(&Ray2d::new(vec2(0.0, 0.0), vec2(1.0, 0.0), 10.0)).unwrap();
+    /// assert_eq!(handle, near);
+    /// assert_eq!(hit.distance, 2.0);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The handle of the nearest object hit by the ray and where it was hit, see [`Ray2d::cast`].
+    #[must_use]
+    pub fn raycast(&self, ray: &Ray2d) -> Option<(BodyHandle, RayHit)> {
+        self.bodies()
+            .filter_map(|(handle, obj)| ray.cast(obj).map(|hit| (handle, hit)))
+            .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance))
+    }
+
+    /// Keeps the objects of the world inside an axis-aligned box, see [`BoundsBehaviour`].
+    ///
+    /// The bounds are applied at the end of every step to the awake dynamic objects and the
+    /// kinematic ones, using the extent of their shape.
+    ///
+    /// # Parameters
+    ///
+    /// - `aabb`: The minimum and maximum corners of the bounds.
+    /// - `behaviour`: What happens to the objects reaching the bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// #     world::{bounds::BoundsBehaviour, World2d},
+    /// # };
+    /// let mut world = World2d::new();
+    /// world.set_bounds((vec2(0.0, 0.0), vec2(800.0, 600.0)), BoundsBehaviour::Despawn);
+    /// let spark = world.add_body(Object2dBuilder::new().mass(1.0).position(vec2(799.0, 300.0)).velocity(vec2(120.0, 0.0)).build());
+    ///
+    /// world.step(1.0 / 60.0);
+    /// assert!(world.body(spark).is_none());
+    /// ```
+    pub fn set_bounds(&mut self, aabb: (Vec2d, Vec2d), behaviour: BoundsBehaviour) {
+        self.bounds = Some((aabb, behaviour));
+    }
+
+    /// Removes the bounds of the world, letting objects go anywhere.
+    pub fn clear_bounds(&mut self) {
+        self.bounds = None;
+    }
+
+    /// Returns the bounds of the world and what happens to the objects reaching them.
+    #[must_use]
+    pub fn bounds(&self) -> Option<((Vec2d, Vec2d), BoundsBehaviour)> {
+        self.bounds
+    }
+
+    /// Returns the simulation time of the world, the sum of every `delta_time` it was stepped by.
+    #[must_use]
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Applies a force to every object of the world at the current simulation time, see
+    /// [`TimedForce::apply_2d_at`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::{combinator::ForceExt, field::uniform},
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// #     world::World2d,
+    /// # };
+    /// let mut world = World2d::new();
+    /// let ball = world.add_body(Object2dBuilder::new().mass(1.0).build());
+    /// let push = uniform(vec2(2.0, 0.0)).active_between(1.0, 2.0);
+    ///
+    /// for _ in 0..4 {
+    ///     world.apply(&push);
+    ///     world.step(0.5);
+    /// }
+    ///
+    /// assert_eq!(world.time(), 2.0);
+    /// assert_eq!(world.body(ball).unwrap().velocity, vec2(2.0, 0.0));
+    /// ```
+    pub fn apply<F: TimedForce>(&mut self, force: &F) {
+        let time = self.time;
+        for (_, obj) in self.bodies_mut() {
+            obj.apply_at(force, time);
+        }
+    }
+
+    /// Adds the impulse of an explosion to every object of the world, see [`Explosion::apply`].
+    pub fn explode(&mut self, explosion: &Explosion) {
+        for index in 0..self.bodies.len() {
+            let Some(obj) = &self.bodies[index] else {
+                continue;
+            };
+            let occluders = self
+                .bodies
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .filter_map(|(_, obj)| obj.as_ref());
+            let impulse = explosion.impulse_on(obj, occluders);
+            if let Some(obj) = &mut self.bodies[index] {
+                obj.add_impulse(impulse);
+            }
+        }
+    }
+
+    /// Advances every object of the world by `delta_time` seconds.
+    ///
+    /// The gravity of the world is added to the accumulated accelerations of every object, so
+    /// objects without mass fall as well, then each
+    /// object is integrated with its own integrator, or with the integrator of the world if it
+    /// has none, consuming the forces and impulses accumulated since the last step. Then the joints
+    /// and the contacts between objects, see [`ContactConstraint`], are solved together with
+    /// `solver_iterations` iterations, the bounds of the world are applied, see
+    /// [`World2d::set_bounds`], and the simulation time of the world is advanced. The
+    /// impulses of the contacts that persist from one step to the next are warm started, so
+    /// stacks of objects come to rest.
+    ///
+    /// Sleeping objects are neither integrated nor tested for collisions against other resting
+    /// objects. They wake up when an awake object touches them or when they have a velocity, a
+    /// force, an impulse or an acceleration at the start of the step. At the end of the step the islands of
+    /// objects are computed and put to sleep or woken up together, see [`Sleep`].
+    ///
+    /// # Parameters
+    ///
+    /// - `delta_time`: The time step in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     collision::shape::Shape,
+    /// #     obj::{body_type::BodyType, obj_2d::Object2dBuilder},
+    /// #     vec::vec_2d::vec2,
+    /// #     world::World2dBuilder,
+    /// # };
+    /// let mut world = World2dBuilder::new().gravity(vec2(0.0, 9.807)).build();
+    /// world.add_body(
+    ///     Object2dBuilder::new()
+    ///         .shape(Shape::AABB(vec2(-5.0, 0.0), vec2(5.0, 1.0)))
+    ///         .body_type(BodyType::Static)
+    ///         .build(),
+    /// );
+    /// let crates: Vec<_> = (0..10)
+    ///     .map(|i| {
+    ///         let crate_ = Object2dBuilder::new()
+    ///             .mass(1.0)
+    ///             .position(vec2(0.0, -0.5 - i as f32))
+    ///             .shape(Shape::AABB(vec2(-0.5, -0.5), vec2(0.5, 0.5)));
+    ///         world.add_body(crate_.build())
+    ///     })
+    ///     .collect();
+    ///
+    /// for _ in 0..300 {
+    ///     world.step(1.0 / 60.0);
+    /// }
+    ///
+    /// // The stack neither jitters nor sinks into itself.
+    /// for (i, crate_) in crates.into_iter().enumerate() {
+    ///     let crate_ = world.body(crate_).unwrap();
+    ///     assert!(crate_.velocity.magnitude() < 1e-3);
+    ///     assert!((crate_.vec.y + 0.5 + i as f32).abs() < 0.2);
+    /// }
+    /// ```
+    pub fn step(&mut self, delta_time: f32) {
+        self.advance(delta_time, |obj, integrator| {
+            obj.integrate_with(integrator, delta_time);
+        });
+    }
+
+    /// Advances every object of the world by `delta_time` seconds like [`World2d::step`], with
+    /// `force` evaluated by the integrators at every position and velocity they sample, see
+    /// [`Object2d::integrate_under`].
+    ///
+    /// Forces applied with [`World2d::apply`] stay constant during the step, so every
+    /// integrator follows the same trajectory under them. Springs, drag and fields that depend
+    /// on where an object is or how fast it moves should be passed here instead for
+    /// [`IntegratorKind::VelocityVerlet`] and [`IntegratorKind::Rk4`] to be more accurate. The
+    /// force is evaluated at the simulation time of each sample and does not wake sleeping
+    /// objects.
+    ///
+    /// # Parameters
+    ///
+    /// - `force`: The force evaluated by the integrators.
+    /// - `delta_time`: The time step in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use motion::{
+    /// #     forces::field::field_fn,
+    /// #     integrators::integrator::IntegratorKind,
+    /// #     obj::obj_2d::Object2dBuilder,
+    /// #     vec::vec_2d::vec2,
+    /// #     world::World2dBuilder,
+    /// # };
+    /// let spring = field_fn(|position| -position);
+    /// let oscillate = |integrator| {
+    ///     let mut world = World2dBuilder::new().integrator(integrator).build();
+    ///     let ball = world.add_body(
+    ///         Object2dBuilder::new()
+    ///             .mass(1.0)
+    ///             .position(vec2(1.0, 0.0))
+    ///             .build(),
+    ///     );
+    ///     for _ in 0..10 {
+    ///         world.step_under(&spring, 0.5);
+    ///     }
+    ///     world.body(ball).unwrap().vec.x
+    /// };
+    ///
+    /// // After 5 seconds the ball should be at cos(5) ≈ 0.28366, RK4 stays much closer to it.
+    /// let verlet = oscillate(IntegratorKind::VelocityVerlet);
+    /// let rk4 = oscillate(IntegratorKind::Rk4);
+    /// assert!((rk4 - 0.28366).abs() < 5e-3);
+    /// assert!((verlet - 0.28366).abs() > 4e-2);
+    /// ```
+    pub fn step_under<F: TimedForce>(&mut self, force: &F, delta_time: f32) {
+        let time = self.time;
+        self.advance(delta_time, |obj, integrator| {
+            obj.integrate_under(integrator, force, time, delta_time);
+        });
+    }
+
+    /// Steps the world, integrating each awake object with `integrate` and the integrator it
+    /// uses.
+    fn advance<I: Fn(&mut Object2d, &IntegratorKind)>(&mut self, delta_time: f32, integrate: I) {
+        let gravity = Gravity {
+            acceleration: self.gravity,
+        };
+        let integrator = self.integrator;
+        for (_, obj) in self.bodies_mut() {
+            if obj.sleeping {
+                if !is_disturbed(obj) {
+                    continue;
+                }
+                obj.wake();
+            }
+            obj.apply(&gravity);
+            let integrator = obj.integrator.unwrap_or(integrator);
+            integrate(obj, &integrator);
+        }
+        self.solve_constraints(delta_time);
+        self.confine();
+        self.update_islands(delta_time);
+        self.time += delta_time;
+    }
+
+    /// Solves every joint and contact of the world with sequential impulses, see [`Constraint`].
+    ///
+    /// The contacts found during the last step are kept, so the ones that persist are warm
+    /// started with the impulses that held them then.
+    fn solve_constraints(&mut self, delta_time: f32) {
+        let mut contacts = self.find_contacts();
+        self.for_each_joint(|_, obj1, obj2| wake_touched(obj1, obj2));
+        for_each_contact(&mut self.bodies, &mut contacts, |_, obj1, obj2| {
+            wake_touched(obj1, obj2);
+        });
+        self.for_each_joint(|joint, obj1, obj2| joint.prepare(obj1, obj2, delta_time));
+        for_each_contact(&mut self.bodies, &mut contacts, |contact, obj1, obj2| {
+            contact.prepare(obj1, obj2, delta_time);
+        });
+        self.for_each_joint(Joint::warm_start);
+        for_each_contact(
+            &mut self.bodies,
+            &mut contacts,
+            ContactConstraint::warm_start,
+        );
+        for _ in 0..self.solver_iterations {
+            self.for_each_joint(Joint::solve_velocity);
+            for_each_contact(
+                &mut self.bodies,
+                &mut contacts,
+                ContactConstraint::solve_velocity,
+            );
+        }
+        for _ in 0..self.solver_iterations {
+            self.for_each_joint(Joint::solve_position);
+            for_each_contact(
+                &mut self.bodies,
+                &mut contacts,
+                ContactConstraint::solve_position,
+            );
+        }
+        self.contacts = contacts;
+    }
+
+    /// Applies the bounds of the world to every moving object, removing the despawned ones.
+    fn confine(&mut self) {
+        let Some((aabb, behaviour)) = self.bounds else {
+            return;
+        };
+        let mut despawned = Vec::new();
+        for (handle, obj) in self.bodies_mut() {
+            if is_moving(obj) && !bounds::confine(obj, aabb, behaviour) {
+                despawned.push(handle);
+            }
+        }
+        for handle in despawned {
+            self.remove_body(handle);
+        }
+    }
+
+    /// Groups the dynamic objects into islands through the contacts and joints of the last step,
+    /// then puts every island whose objects all moved slowly for long enough to sleep and wakes
+    /// up the other ones.
+    fn update_islands(&mut self, delta_time: f32) {
+        let mut islands = Islands::new(self.bodies.len());
+        let dynamic = |bodies: &[Option<Object2d>], handle: BodyHandle| {
+            bodies[handle.0]
+                .as_ref()
+                .is_some_and(|obj| obj.body_type.is_dynamic())
+        };
+        let links = self.contacts.iter().map(|(h1, h2, _)| (*h1, Some(*h2)));
+        let links = links.chain(
+            self.joints
+                .iter()
+                .flatten()
+                .map(|slot| (slot.body1, slot.body2)),
+        );
+        for (handle1, handle2) in links {
+            if let Some(handle2) = handle2 {
+                if dynamic(&self.bodies, handle1) && dynamic(&self.bodies, handle2) {
+                    islands.union(handle1.0, handle2.0);
+                }
+            }
+        }
+
+        let sleep = self.sleep;
+        let mut sleep_time = vec![f32::INFINITY; self.bodies.len()];
+        let mut awake = vec![false; self.bodies.len()];
+        for (handle, obj) in self.bodies_mut() {
+            if !obj.body_type.is_dynamic() {
+                continue;
+            }
+            if !obj.sleeping {
+                if sleep.is_some_and(|sleep| sleep.is_slow(obj)) {
+                    obj.sleep_time += delta_time;
+                } else {
+                    obj.sleep_time = 0.0;
+                }
+            }
+            let island = islands.find(handle.0);
+            sleep_time[island] = sleep_time[island].min(obj.sleep_time);
+            awake[island] |= !obj.sleeping;
+        }
+
+        for (handle, obj) in self.bodies_mut() {
+            let island = islands.find(handle.0);
+            if !obj.body_type.is_dynamic() || !awake[island] {
+                continue;
+            }
+            if sleep.is_some_and(|sleep| sleep_time[island] >= sleep.time) {
+                obj.sleep();
+            } else if obj.sleeping {
+                obj.wake();
+            }
+        }
+    }
+
+    /// Calls `f` with every joint of the world and the objects it connects, skipping the joints
+    /// between resting objects.
+    fn for_each_joint<F: FnMut(&mut Joint, &mut Object2d, &mut Object2d)>(&mut self, mut f: F) {
+        let mut ground = Object2dBuilder::new().body_type(BodyType::Static).build();
+        for slot in self.joints.iter_mut().flatten() {
+            let pair = match slot.body2 {
+                Some(body2) => pair_mut(&mut self.bodies, slot.body1, body2),
+                None => self
+                    .bodies
+                    .get_mut(slot.body1.0)
+                    .and_then(Option::as_mut)
+                    .map(|obj1| (obj1, &mut ground)),
+            };
+            if let Some((obj1, obj2)) =
+                pair.filter(|(obj1, obj2)| is_moving(obj1) || is_moving(obj2))
+            {
+                f(&mut slot.joint, obj1, obj2);
+            }
+        }
+    }
+
+    /// Detects the collisions between every pair of objects where at least one is dynamic.
+    ///
+    /// Pairs of resting objects are not tested, the contact they had when they fell asleep is
+    /// kept instead.
+    fn find_contacts(&self) -> Vec<(BodyHandle, BodyHandle, ContactConstraint)> {
+        let mut contacts = Vec::new();
+        for (handle1, obj1) in self.bodies() {
+            for (handle2, obj2) in self
+                .bodies()
+                .skip_while(|(handle2, _)| handle2.0 <= handle1.0)
+            {
+                if !obj1.body_type.is_dynamic() && !obj2.body_type.is_dynamic() {
+                    continue;
+                }
+                // Both lists are sorted by handles, so the previous contacts of the pair, one for
+                // each pair of touching children, can be looked up with a binary search.
+                let key = (handle1.0, handle2.0);
+                let start = self
+                    .contacts
+                    .partition_point(|(h1, h2, _)| (h1.0, h2.0) < key);
+                let previous = self.contacts[start..]
+                    .iter()
+                    .take_while(|(h1, h2, _)| (h1.0, h2.0) == key)
+                    .map(|(_, _, contact)| contact);
+                if !is_moving(obj1) && !is_moving(obj2) {
+                    contacts.extend(previous.map(|contact| (handle1, handle2, *contact)));
+                    continue;
+                }
+                for child_contact in Collision2d::new(obj1.clone(), obj2.clone()).contacts() {
+                    let mut contact = ContactConstraint::from(child_contact);
+                    // Children touching at several points match the nearest previous point.
+                    let point = contact.contact.point;
+                    if let Some(previous) = previous
+                        .clone()
+                        .filter(|previous| {
+                            (previous.child1, previous.child2) == (contact.child1, contact.child2)
+                        })
+                        .min_by(|previous1, previous2| {
+                            let distance1 = previous1.contact.point.distance(point);
+                            let distance2 = previous2.contact.point.distance(point);
+                            distance1.total_cmp(&distance2)
+                        })
+                    {
+                        contact = contact.warm_started(previous);
+                    }
+                    contacts.push((handle1, handle2, contact));
+                }
+            }
+        }
+        contacts
+    }
+}
+
+/// Calls `f` with every contact and the objects it involves.
+fn for_each_contact<F: FnMut(&mut ContactConstraint, &mut Object2d, &mut Object2d)>(
+    bodies: &mut [Option<Object2d>],
+    contacts: &mut [(BodyHandle, BodyHandle, ContactConstraint)],
+    mut f: F,
+) {
+    for (handle1, handle2, contact) in contacts {
+        if let Some((obj1, obj2)) = pair_mut(bodies, *handle1, *handle2)
+            .filter(|(obj1, obj2)| is_moving(obj1) || is_moving(obj2))
+        {
+            f(contact, obj1, obj2);
+        }
+    }
+}
+
+/// Returns whether an object may push the objects it touches: an awake dynamic object or a
+/// moving kinematic one.
+fn is_moving(obj: &Object2d) -> bool {
+    obj.is_awake()
+        || obj.body_type.is_kinematic()
+            && (obj.velocity != Vec2d::ZERO || obj.angular_velocity != 0.0)
+}
+
+/// Returns whether something was done to a sleeping object that should wake it up.
+fn is_disturbed(obj: &Object2d) -> bool {
+    obj.velocity != Vec2d::ZERO
+        || obj.angular_velocity != 0.0
+        || obj.force != Vec2d::ZERO
+        || obj.torque != 0.0
+        || obj.impulse != Vec2d::ZERO
+        || obj.added_acceleration != Vec2d::ZERO
+}
+
+/// Wakes up either object if it sleeps and the other one moves into it.
+fn wake_touched(obj1: &mut Object2d, obj2: &mut Object2d) {
+    if obj1.sleeping && is_moving(obj2) {
+        obj1.wake();
+    }
+    if obj2.sleeping && is_moving(obj1) {
+        obj2.wake();
+    }
+}
+
+/// Returns mutable references to two different objects of `bodies`.
+fn pair_mut(
+    bodies: &mut [Option<Object2d>],
+    handle1: BodyHandle,
+    handle2: BodyHandle,
+) -> Option<(&mut Object2d, &mut Object2d)> {
+    let (i, j) = (handle1.0, handle2.0);
+    if i == j || i.max(j) >= bodies.len() {
+        return None;
+    }
+    let (head, tail) = bodies.split_at_mut(i.max(j));
+    let (low, high) = (head[i.min(j)].as_mut()?, tail[0].as_mut()?);
+    if i < j {
+        Some((low, high))
+    } else {
+        Some((high, low))
+    }
+}
+
+/// Builder pattern for constructing a `World2d`.
+#[derive(Debug)]
+pub struct World2dBuilder {
+    gravity: Vec2d,
+    integrator: IntegratorKind,
+    solver_iterations: usize,
+    sleep: Option<Sleep>,
+}
+
+impl Default for World2dBuilder {
+    fn default() -> Self {
+        Self {
+            gravity: Vec2d::ZERO,
+            integrator: IntegratorKind::default(),
+            solver_iterations: DEFAULT_SOLVER_ITERATIONS,
+            sleep: Some(Sleep::default()),
+        }
+    }
+}
+
+impl World2dBuilder {
+    /// Creates a new `World2dBuilder` with the default configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the gravitational acceleration of the world, there is no gravity by default.
+    #[must_use]
+    pub fn gravity(mut self, gravity: Vec2d) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the integrator used for the objects that do not select their own.
+    ///
+    /// Forces accumulated before a step stay constant during it, integrators only differ under
+    /// the forces passed to [`World2d::step_under`].
+    #[must_use]
+    pub fn integrator(mut self, integrator: IntegratorKind) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// Sets the number of velocity and position iterations used to solve the joints and contacts.
+    #[must_use]
+    pub fn solver_iterations(mut self, solver_iterations: usize) -> Self {
+        self.solver_iterations = solver_iterations;
+        self
+    }
+
+    /// Sets when objects fall asleep, `None` keeps every object awake.
+    #[must_use]
+    pub fn sleep(mut self, sleep: Option<Sleep>) -> Self {
+        self.sleep = sleep;
+        self
+    }
+
+    /// Builds the `World2d` with the specified configuration.
+    #[must_use]
+    pub fn build(self) -> World2d {
+        World2d {
+            gravity: self.gravity,
+            integrator: self.integrator,
+            solver_iterations: self.solver_iterations,
+            sleep: self.sleep,
+            bodies: Vec::new(),
+            joints: Vec::new(),
+            contacts: Vec::new(),
+            bounds: None,
+            time: 0.0,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::obj::obj_2d::Object2d;
+
+/// When the objects of a [`World2d`](super::World2d) fall asleep.
+///
+/// Objects are grouped into islands, the sets of dynamic objects touching each other or connected
+/// by joints. An island falls asleep once every one of its objects has moved slower than the
+/// thresholds for `time` seconds, and wakes up as soon as one of them is touched, pushed or given
+/// a velocity.
+///
+/// # Fields
+///
+/// - `linear_velocity`: The speed below which an object may fall asleep.
+/// - `angular_velocity`: The angular speed below which an object may fall asleep, in radians per second.
+/// - `time`: How long an island must stay below the thresholds before it falls asleep, in seconds.
+///
+/// # Examples
+///
+/// ```
+/// # use motion::{
+/// #     collision::shape::Shape,
+/// #     obj::{body_type::BodyType, obj_2d::Object2dBuilder},
+/// #     vec::vec_2d::vec2,
+/// #     world::{sleep::Sleep, World2dBuilder},
+/// # };
+/// let mut world = World2dBuilder::new()
+///     .gravity(vec2(0.0, 9.807))
+///     .sleep(Some(Sleep { time: 0.25, ..Sleep::default() }))
+///     .build();
+/// world.add_body(
+///     Object2dBuilder::new()
+///         .shape(Shape::AABB(vec2(-5.0, 0.0), vec2(5.0, 1.0)))
+///         .body_type(BodyType::Static)
+///         .build(),
+/// );
+/// let crate_ = world.add_body(
+///     Object2dBuilder::new()
+///         .mass(1.0)
+///         .position(vec2(0.0, -0.5))
+///         .shape(Shape::AABB(vec2(-0.5, -0.5), vec2(0.5, 0.5)))
+///         .build(),
+/// );
+///
+/// for _ in 0..60 {
+///     world.step(1.0 / 60.0);
+/// }
+/// assert!(world.body(crate_).unwrap().sleeping);
+///
+/// // A push wakes the crate up.
+/// world.body_mut(crate_).unwrap().add_impulse(vec2(1.0, 0.0));
+/// world.step(1.0 / 60.0);
+/// assert!(!world.body(crate_).unwrap().sleeping);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sleep {
+    pub linear_velocity: f32,
+    pub angular_velocity: f32,
+    pub time: f32,
+}
+
+impl Default for Sleep {
+    /// Objects slower than `0.05` units and `2` degrees per second for half a second fall asleep.
+    fn default() -> Self {
+        Sleep {
+            linear_velocity: 0.05,
+            angular_velocity: 0.035,
+            time: 0.5,
+        }
+    }
+}
+
+impl Sleep {
+    /// Returns whether an object moves slowly enough to fall asleep.
+    #[must_use]
+    pub fn is_slow(&self, obj: &Object2d) -> bool {
+        obj.velocity.magnitude() <= self.linear_velocity
+            && obj.angular_velocity.abs() <= self.angular_velocity
+    }
+}